@@ -0,0 +1,56 @@
+//! Reports token, exchange and coverage drift between the variants of each
+//! script family in `variants.toml`.
+//!
+//! Usage: `cargo run -p os-build --bin os-drift -- [--check] [<variants.toml>]`
+//!
+//! With `--check`, exits 1 on drift the family does not allow, or on allowed
+//! drift that no longer occurs.
+
+use os_build::drift::{family_drift, load_families, VARIANTS_FILE};
+use std::path::PathBuf;
+use std::process::exit;
+
+fn main() {
+    let mut check = false;
+    let mut path = PathBuf::from(VARIANTS_FILE);
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            _ if !arg.starts_with('-') => path = PathBuf::from(arg),
+            _ => {
+                eprintln!("usage: os-drift [--check] [<variants.toml>]");
+                exit(2);
+            }
+        }
+    }
+    let scripts_dir = path
+        .parent()
+        .map(|root| root.join("scripts"))
+        .unwrap_or_default();
+
+    let families = load_families(&path).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        exit(1)
+    });
+    let mut clean = true;
+    for family in families.iter() {
+        let drift = family_drift(&scripts_dir, family).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            exit(1)
+        });
+        println!("{}", family.name);
+        for line in drift.unintended.iter() {
+            println!("  {}", line);
+        }
+        for line in drift.allowed.iter() {
+            println!("  {} (allowed)", line);
+        }
+        for line in drift.stale.iter() {
+            println!("  {} (allowed, no longer occurs)", line);
+        }
+        clean &= drift.is_clean();
+    }
+    if check && !clean {
+        exit(1);
+    }
+}
@@ -0,0 +1,33 @@
+//! Regenerates `src/table.rs` of price scripts from their `coverage.toml`.
+//!
+//! Usage: `cargo run -p os-build --bin os-gen -- [--check] <script dir>...`
+
+use os_build::generate_table;
+use std::path::Path;
+use std::process::exit;
+
+fn main() {
+    let mut check = false;
+    let mut script_dirs = Vec::new();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            _ => script_dirs.push(arg),
+        }
+    }
+    if script_dirs.is_empty() {
+        eprintln!("usage: os-gen [--check] <script dir>...");
+        exit(2);
+    }
+
+    let mut failed = false;
+    for script_dir in script_dirs.iter() {
+        if let Err(err) = generate_table(Path::new(script_dir), check) {
+            eprintln!("{}", err);
+            failed = true;
+        }
+    }
+    if failed {
+        exit(1);
+    }
+}
@@ -0,0 +1,524 @@
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// Name of the coverage table in a price script's crate directory.
+pub const COVERAGE_FILE: &str = "coverage.toml";
+/// Name of the generated file, relative to the script's crate directory.
+pub const TABLE_FILE: &str = "src/table.rs";
+
+/// Token x exchange coverage table of a price oracle script.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Coverage {
+    /// Table version, echoed in the generated header.
+    pub version: Option<u64>,
+    /// Exchanges in column order. Their positions are the external IDs.
+    pub exchanges: Vec<ExchangeColumn>,
+    /// Exchanges a symbol's rate needs prices from, unless its token says
+    /// otherwise. 1 if left out.
+    pub min_sources: Option<u8>,
+    /// '0'/'1' coverage string of every symbol, one character per exchange
+    /// column, or `{ coverage = <string>, min_sources = <n> }`. Keys are the
+    /// ticker spellings requesters use.
+    pub tokens: toml::Table,
+    /// Other spellings of a symbol, mapped to its key in `tokens`.
+    #[serde(default)]
+    pub aliases: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExchangeColumn {
+    pub name: String,
+    /// Whether the exchange has a data source of its own rather than going
+    /// through CCXT.
+    #[serde(default)]
+    pub api: bool,
+}
+
+impl Coverage {
+    pub fn load(path: &Path) -> Result<Coverage, String> {
+        let raw = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        toml::from_str(&raw).map_err(|e| e.to_string())
+    }
+
+    /// Returns `(symbol, coverage string)` pairs in table order.
+    pub fn token_rows(&self) -> Result<Vec<(&str, &str)>, String> {
+        self.token_quorums().map(|rows| {
+            rows.into_iter()
+                .map(|(symbol, coverage, _)| (symbol, coverage))
+                .collect()
+        })
+    }
+
+    /// Returns `(symbol, coverage string, min_sources)` in table order.
+    pub fn token_quorums(&self) -> Result<Vec<(&str, &str, u8)>, String> {
+        let default = self.min_sources.unwrap_or(1);
+        self.tokens
+            .iter()
+            .map(|(symbol, token)| {
+                if let Some(coverage) = token.as_str() {
+                    return Ok((symbol.as_str(), coverage, default));
+                }
+                let invalid = || format!("token `{}` is neither a coverage string nor a table of coverage and min_sources", symbol);
+                let table = token.as_table().ok_or_else(invalid)?;
+                if table
+                    .keys()
+                    .any(|key| key != "coverage" && key != "min_sources")
+                {
+                    return Err(invalid());
+                }
+                let coverage = table
+                    .get("coverage")
+                    .and_then(|c| c.as_str())
+                    .ok_or_else(invalid)?;
+                let min_sources = match table.get("min_sources") {
+                    Some(n) => n
+                        .as_integer()
+                        .and_then(|n| u8::try_from(n).ok())
+                        .ok_or_else(invalid)?,
+                    None => default,
+                };
+                Ok((symbol.as_str(), coverage, min_sources))
+            })
+            .collect()
+    }
+
+    /// Returns the alias table of the generated registry, keyed by
+    /// uppercase spelling. Besides the explicit `aliases`, every mixed-case
+    /// symbol is reachable through its uppercase spelling unless that
+    /// spelling is a symbol itself or is shared by two symbols.
+    pub fn alias_rows(&self) -> Result<BTreeMap<String, String>, String> {
+        let symbols: HashSet<&str> = self.tokens.keys().map(|s| s.as_str()).collect();
+        let mut folded: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+        for symbol in self.tokens.keys() {
+            folded
+                .entry(symbol.to_ascii_uppercase())
+                .or_default()
+                .push(symbol);
+        }
+        let mut aliases: BTreeMap<String, String> = folded
+            .into_iter()
+            .filter(|(upper, spellings)| spellings.len() == 1 && !symbols.contains(upper.as_str()))
+            .map(|(upper, spellings)| (upper, spellings[0].to_string()))
+            .collect();
+
+        for (alias, symbol) in self.aliases.iter() {
+            check_symbol(alias)?;
+            if !symbols.contains(symbol.as_str()) {
+                return Err(format!(
+                    "alias `{}` names unknown symbol `{}`",
+                    alias, symbol
+                ));
+            }
+            let upper = alias.to_ascii_uppercase();
+            if symbols.contains(upper.as_str()) || symbols.contains(alias.as_str()) {
+                return Err(format!("alias `{}` shadows a symbol", alias));
+            }
+            aliases.insert(upper, symbol.clone());
+        }
+        Ok(aliases)
+    }
+
+    /// Returns the table with the coverage of some tokens replaced, e.g. by
+    /// the `coverage` of a network profile. `overrides` has the form of
+    /// `tokens` and only names tokens of the table.
+    pub fn with_overrides(&self, overrides: &toml::Table) -> Result<Coverage, String> {
+        let mut coverage = self.clone();
+        for (symbol, token) in overrides.iter() {
+            match coverage.tokens.get_mut(symbol) {
+                Some(row) => *row = token.clone(),
+                None => return Err(format!("coverage of unknown token `{}`", symbol)),
+            }
+        }
+        coverage.validate()?;
+        Ok(coverage)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.exchanges.is_empty() {
+            return Err("table has no exchanges".into());
+        }
+        let mut names = HashSet::new();
+        for exchange in self.exchanges.iter() {
+            check_ident(&exchange.name)?;
+            if !names.insert(exchange.name.as_str()) {
+                return Err(format!("exchange `{}` is listed twice", exchange.name));
+            }
+        }
+        for (symbol, coverage, min_sources) in self.token_quorums()? {
+            check_symbol(symbol)?;
+            if coverage.len() != self.exchanges.len() {
+                return Err(format!(
+                    "coverage of `{}` has {} columns, expected {}",
+                    symbol,
+                    coverage.len(),
+                    self.exchanges.len()
+                ));
+            }
+            if coverage.chars().any(|c| c != '0' && c != '1') {
+                return Err(format!("coverage of `{}` is not a '0'/'1' string", symbol));
+            }
+            // A token no exchange prices is listed but never priced
+            let sources = coverage.matches('1').count();
+            if min_sources == 0 || (sources > 0 && min_sources as usize > sources) {
+                return Err(format!(
+                    "`{}` needs {} sources but {} exchanges price it",
+                    symbol, min_sources, sources
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders the table as the Rust items a price script `include!`s:
+    /// `EXCHANGE_COUNT`, `API_SOURCE`, the `Exchange` enum, the `SYMBOLS`
+    /// registry and its `ALIASES`.
+    ///
+    /// The output depends on the table alone, so regenerating an unchanged
+    /// table reproduces the checked-in file byte for byte.
+    pub fn render(&self) -> Result<String, String> {
+        self.validate()?;
+        let tokens = self.token_quorums()?;
+        let aliases = self.alias_rows()?;
+        let api_source: Vec<&ExchangeColumn> = self.exchanges.iter().filter(|e| e.api).collect();
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "// This file was automatically generated by os-gen from {}. Do not edit.",
+            COVERAGE_FILE
+        )
+        .unwrap();
+        if let Some(version) = self.version {
+            writeln!(out, "// VERSION = {}", version).unwrap();
+        }
+        writeln!(out).unwrap();
+        writeln!(out, "const EXCHANGE_COUNT: u64 = {};", self.exchanges.len()).unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "const API_SOURCE: [Exchange; {}] = [",
+            api_source.len()
+        )
+        .unwrap();
+        for exchange in api_source.iter() {
+            writeln!(out, "    Exchange::{},", exchange.name).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "#[derive(Display, EnumString, EnumIter, Debug, Copy, Clone, PartialEq)]"
+        )
+        .unwrap();
+        writeln!(out, "enum Exchange {{").unwrap();
+        for (i, exchange) in self.exchanges.iter().enumerate() {
+            writeln!(out, "    {} = {},", exchange.name, i).unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+
+        writeln!(out).unwrap();
+        writeln!(out, "static SYMBOLS: SymbolMap = phf_map! {{").unwrap();
+        for (symbol, coverage, min_sources) in tokens.iter() {
+            let covering: Vec<&str> = coverage
+                .chars()
+                .zip(self.exchanges.iter())
+                .filter(|(bit, _)| *bit == '1')
+                .map(|(_, exchange)| exchange.name.as_str())
+                .collect();
+            writeln!(
+                out,
+                "    {:?} => SymbolInfo {{ exchanges: exchanges![{}], min_sources: {} }},",
+                symbol,
+                covering.join(", "),
+                min_sources
+            )
+            .unwrap();
+        }
+        writeln!(out, "}};").unwrap();
+
+        writeln!(out).unwrap();
+        writeln!(out, "static ALIASES: AliasMap = phf_map! {{").unwrap();
+        for (alias, symbol) in aliases.iter() {
+            writeln!(out, "    {:?} => {:?},", alias, symbol).unwrap();
+        }
+        writeln!(out, "}};").unwrap();
+
+        Ok(out)
+    }
+}
+
+fn check_ident(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("`{}` is not a valid Rust identifier", name))
+    }
+}
+
+// Symbols are space-separated in calldata and prices comma-separated in
+// reports, so neither may appear in a ticker.
+fn check_symbol(symbol: &str) -> Result<(), String> {
+    if symbol.is_empty()
+        || symbol
+            .chars()
+            .any(|c| c.is_whitespace() || c == ',' || !c.is_ascii_graphic())
+    {
+        Err(format!("`{}` is not a valid symbol", symbol))
+    } else {
+        Ok(())
+    }
+}
+
+/// Regenerates `src/table.rs` of the script in `script_dir` from its
+/// `coverage.toml`. With `check` set the file is left alone and an error is
+/// returned if it is out of date.
+pub fn generate_table(script_dir: &Path, check: bool) -> Result<(), String> {
+    let coverage_path = script_dir.join(COVERAGE_FILE);
+    let table_path = script_dir.join(TABLE_FILE);
+    let rendered = Coverage::load(&coverage_path)
+        .and_then(|coverage| coverage.render())
+        .map_err(|err| format!("{}: {}", coverage_path.display(), err))?;
+
+    if check {
+        match fs::read_to_string(&table_path) {
+            Ok(current) if current == rendered => Ok(()),
+            _ => Err(format!("{} is out of date", table_path.display())),
+        }
+    } else {
+        fs::write(&table_path, rendered).map_err(|e| format!("{}: {}", table_path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn parse(raw: &str) -> Coverage {
+        toml::from_str(raw).unwrap()
+    }
+
+    #[test]
+    fn test_render() {
+        let coverage = parse(
+            r#"
+            version = 1
+            exchanges = [
+                { name = "COINGECKO", api = true },
+                { name = "BINANCE" },
+            ]
+
+            [tokens]
+            ETH = { coverage = "11", min_sources = 2 }
+            BTC = "10"
+            "#,
+        );
+        assert_eq!(
+            coverage.render().unwrap(),
+            r#"// This file was automatically generated by os-gen from coverage.toml. Do not edit.
+// VERSION = 1
+
+const EXCHANGE_COUNT: u64 = 2;
+
+const API_SOURCE: [Exchange; 1] = [
+    Exchange::COINGECKO,
+];
+
+#[derive(Display, EnumString, EnumIter, Debug, Copy, Clone, PartialEq)]
+enum Exchange {
+    COINGECKO = 0,
+    BINANCE = 1,
+}
+
+static SYMBOLS: SymbolMap = phf_map! {
+    "ETH" => SymbolInfo { exchanges: exchanges![COINGECKO, BINANCE], min_sources: 2 },
+    "BTC" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+};
+
+static ALIASES: AliasMap = phf_map! {
+};
+"#
+        );
+    }
+
+    #[test]
+    fn test_render_keeps_symbol_spelling() {
+        let coverage = parse(
+            r#"
+            exchanges = [{ name = "BINANCE" }, { name = "TERRASWAP", api = true }]
+
+            [tokens]
+            1INCH = "10"
+            bLUNA = "01"
+            "#,
+        );
+        let rendered = coverage.render().unwrap();
+        assert!(rendered.contains(
+            "    \"1INCH\" => SymbolInfo { exchanges: exchanges![BINANCE], min_sources: 1 },\n"
+        ));
+        assert!(rendered.contains(
+            "    \"bLUNA\" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },\n"
+        ));
+    }
+
+    #[test]
+    fn test_alias_rows() {
+        let coverage = parse(
+            r#"
+            exchanges = [{ name = "BINANCE" }]
+
+            [tokens]
+            CNY = "1"
+            Psi = "1"
+            mETH = "1"
+            METH = "1"
+            bLuna = "1"
+            BLuna = "1"
+
+            [aliases]
+            rmb = "CNY"
+            "#,
+        );
+        let aliases = coverage.alias_rows().unwrap();
+        assert_eq!(
+            aliases.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("PSI".to_string(), "Psi".to_string()),
+                ("RMB".to_string(), "CNY".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_rejects_bad_tables() {
+        let wrong_width = r#"
+            exchanges = [{ name = "BINANCE" }]
+            [tokens]
+            BTC = "10"
+        "#;
+        let not_binary = r#"
+            exchanges = [{ name = "BINANCE" }]
+            [tokens]
+            BTC = "x"
+        "#;
+        let bad_symbol = r#"
+            exchanges = [{ name = "BINANCE" }]
+            [tokens]
+            "BTC USD" = "1"
+        "#;
+        let duplicate_exchange = r#"
+            exchanges = [{ name = "BINANCE" }, { name = "BINANCE" }]
+            [tokens]
+        "#;
+        let unknown_alias_target = r#"
+            exchanges = [{ name = "BINANCE" }]
+            [tokens]
+            BTC = "1"
+            [aliases]
+            XBT = "BITCOIN"
+        "#;
+        let alias_shadows_symbol = r#"
+            exchanges = [{ name = "BINANCE" }]
+            [tokens]
+            BTC = "1"
+            ETH = "1"
+            [aliases]
+            eth = "BTC"
+        "#;
+        let unreachable_quorum = r#"
+            exchanges = [{ name = "BINANCE" }, { name = "KRAKEN" }]
+            [tokens]
+            BTC = { coverage = "10", min_sources = 2 }
+        "#;
+        let default_quorum_too_high = r#"
+            exchanges = [{ name = "BINANCE" }, { name = "KRAKEN" }]
+            min_sources = 2
+            [tokens]
+            BTC = "10"
+        "#;
+        let zero_quorum = r#"
+            exchanges = [{ name = "BINANCE" }]
+            [tokens]
+            BTC = { coverage = "1", min_sources = 0 }
+        "#;
+        let unknown_token_key = r#"
+            exchanges = [{ name = "BINANCE" }]
+            [tokens]
+            BTC = { coverage = "1", quorum = 1 }
+        "#;
+        for raw in [
+            wrong_width,
+            not_binary,
+            bad_symbol,
+            duplicate_exchange,
+            unknown_alias_target,
+            alias_shadows_symbol,
+            unreachable_quorum,
+            default_quorum_too_high,
+            zero_quorum,
+            unknown_token_key,
+        ] {
+            assert!(parse(raw).render().is_err(), "{}", raw);
+        }
+    }
+
+    #[test]
+    fn test_with_overrides() {
+        let coverage = parse(
+            r#"
+            exchanges = [{ name = "BINANCE" }, { name = "KRAKEN" }]
+
+            [tokens]
+            BTC = "11"
+            ETH = "10"
+            "#,
+        );
+        let overrides: toml::Table =
+            toml::from_str(r#"ETH = { coverage = "11", min_sources = 2 }"#).unwrap();
+        let overridden = coverage.with_overrides(&overrides).unwrap();
+        assert_eq!(
+            overridden.token_quorums().unwrap(),
+            vec![("BTC", "11", 1), ("ETH", "11", 2)]
+        );
+        for raw in [
+            r#"DOGE = "11""#,
+            r#"ETH = "111""#,
+            r#"ETH = { coverage = "10", min_sources = 2 }"#,
+        ] {
+            let overrides: toml::Table = toml::from_str(raw).unwrap();
+            assert!(coverage.with_overrides(&overrides).is_err(), "{}", raw);
+        }
+    }
+
+    #[test]
+    fn test_data_source_ids_are_left_to_profiles() {
+        let with_id = r#"
+            exchanges = [{ name = "COINGECKO", api = true, data_source_id = 11 }]
+            [tokens]
+        "#;
+        assert!(toml::from_str::<Coverage>(with_id).is_err());
+    }
+
+    #[test]
+    fn test_checked_in_tables_are_up_to_date() {
+        let scripts = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../scripts");
+        let mut checked = 0;
+        for entry in fs::read_dir(scripts).unwrap() {
+            let script_dir = entry.unwrap().path();
+            if script_dir.join(COVERAGE_FILE).exists() {
+                generate_table(&script_dir, true).unwrap();
+                checked += 1;
+            }
+        }
+        assert!(checked > 0);
+    }
+}
@@ -0,0 +1,321 @@
+//! Drift between the variants of a script deployed to several networks.
+//!
+//! `variants.toml` at the repository root groups variants into families.
+//! A variant is a price script's coverage table, optionally narrowed by one
+//! of its network profiles: `linear_os@testnet` is linear_os without the
+//! `excluded_tokens` of `profiles/testnet.toml`, and with its `coverage`
+//! overrides. Every variant of a family is
+//! compared with the family's first one, and each difference is reported as
+//! one line:
+//!
+//! ```text
+//! linear_os@testnet: token ENJ missing
+//! standard_dataset_crypto_injective: exchange KUCOIN added
+//! standard_dataset_crypto_injective: exchange order differs
+//! standard_dataset_crypto_injective: OHM sources -BINANCE +HUOBIPRO
+//! ```
+//!
+//! In a `subset` family the variants serve part of the reference's tokens,
+//! and only the tokens they add are drift. Lines listed in a family's
+//! `allow` are intended. The `os-drift` binary prints every line and, with
+//! `--check`, fails on the others and on allowed lines that no longer occur.
+
+use crate::coverage::{Coverage, COVERAGE_FILE};
+use crate::profile::Profile;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// Name of the variant families file at the repository root.
+pub const VARIANTS_FILE: &str = "variants.toml";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VariantsFile {
+    family: Vec<Family>,
+}
+
+/// Variants of one script family. The first variant is the reference the
+/// others are compared with.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Family {
+    pub name: String,
+    pub variants: Vec<String>,
+    /// Whether variants serve a subset of the reference's tokens, so the
+    /// tokens they leave out are not drift.
+    #[serde(default)]
+    pub subset: bool,
+    /// Drift lines that are intended.
+    #[serde(default)]
+    pub allow: Vec<String>,
+}
+
+pub fn load_families(path: &Path) -> Result<Vec<Family>, String> {
+    let raw = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let file: VariantsFile =
+        toml::from_str(&raw).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(file.family)
+}
+
+/// Tokens and exchange columns one variant serves.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Variant {
+    pub name: String,
+    /// Exchange names in column order, i.e. by external ID.
+    pub exchanges: Vec<String>,
+    /// Names of the exchanges pricing each token.
+    pub tokens: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Variant {
+    /// Loads the variant `<script>[@<network>]` from `scripts_dir`.
+    pub fn load(scripts_dir: &Path, name: &str) -> Result<Variant, String> {
+        let (script, network) = match name.split_once('@') {
+            Some((script, network)) => (script, Some(network)),
+            None => (name, None),
+        };
+        let script_dir = scripts_dir.join(script);
+        let coverage_path = script_dir.join(COVERAGE_FILE);
+        let coverage = Coverage::load(&coverage_path)
+            .map_err(|e| format!("{}: {}", coverage_path.display(), e))?;
+        let (coverage, excluded) = match network {
+            Some(network) => {
+                let path = script_dir
+                    .join("profiles")
+                    .join(format!("{}.toml", network));
+                let profile =
+                    Profile::load(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+                let coverage = coverage
+                    .with_overrides(&profile.coverage)
+                    .map_err(|e| format!("{}: {}", path.display(), e))?;
+                (coverage, profile.excluded_tokens)
+            }
+            None => (coverage, Vec::new()),
+        };
+        Variant::new(name, &coverage, &excluded)
+    }
+
+    pub fn new(name: &str, coverage: &Coverage, excluded: &[String]) -> Result<Variant, String> {
+        let exchanges: Vec<String> = coverage.exchanges.iter().map(|e| e.name.clone()).collect();
+        let tokens = coverage
+            .token_rows()?
+            .into_iter()
+            .filter(|(symbol, _)| !excluded.iter().any(|e| e == symbol))
+            .map(|(symbol, bits)| {
+                let sources = bits
+                    .chars()
+                    .zip(exchanges.iter())
+                    .filter(|(bit, _)| *bit == '1')
+                    .map(|(_, exchange)| exchange.clone())
+                    .collect();
+                (symbol.to_string(), sources)
+            })
+            .collect();
+        Ok(Variant {
+            name: name.to_string(),
+            exchanges,
+            tokens,
+        })
+    }
+
+    /// Returns how this variant differs from `reference`, one line per
+    /// difference. With `subset`, tokens of `reference` this variant leaves
+    /// out are not reported.
+    pub fn drift(&self, reference: &Variant, subset: bool) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut line = |detail: String| lines.push(format!("{}: {}", self.name, detail));
+
+        for token in reference.tokens.keys() {
+            if !subset && !self.tokens.contains_key(token) {
+                line(format!("token {} missing", token));
+            }
+        }
+        for token in self.tokens.keys() {
+            if !reference.tokens.contains_key(token) {
+                line(format!("token {} added", token));
+            }
+        }
+
+        for exchange in reference.exchanges.iter() {
+            if !self.exchanges.contains(exchange) {
+                line(format!("exchange {} missing", exchange));
+            }
+        }
+        for exchange in self.exchanges.iter() {
+            if !reference.exchanges.contains(exchange) {
+                line(format!("exchange {} added", exchange));
+            }
+        }
+        // Shared exchanges keep their relative order, or external IDs shift
+        let shared = |of: &Variant, with: &Variant| -> Vec<String> {
+            of.exchanges
+                .iter()
+                .filter(|e| with.exchanges.contains(e))
+                .cloned()
+                .collect()
+        };
+        if shared(self, reference) != shared(reference, self) {
+            line("exchange order differs".into());
+        }
+
+        for (token, sources) in self.tokens.iter() {
+            let reference_sources = match reference.tokens.get(token) {
+                Some(sources) => sources,
+                None => continue,
+            };
+            // Only exchanges both variants have can be compared
+            let dropped = reference_sources
+                .difference(sources)
+                .filter(|e| self.exchanges.contains(e))
+                .map(|e| format!(" -{}", e));
+            let added = sources
+                .difference(reference_sources)
+                .filter(|e| reference.exchanges.contains(e))
+                .map(|e| format!(" +{}", e));
+            let changes: String = dropped.chain(added).collect();
+            if !changes.is_empty() {
+                line(format!("{} sources{}", token, changes));
+            }
+        }
+        lines
+    }
+}
+
+/// Drift of a family, split by whether it is intended.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FamilyDrift {
+    pub allowed: Vec<String>,
+    pub unintended: Vec<String>,
+    /// Allowed lines that no longer occur.
+    pub stale: Vec<String>,
+}
+
+impl FamilyDrift {
+    pub fn is_clean(&self) -> bool {
+        self.unintended.is_empty() && self.stale.is_empty()
+    }
+}
+
+pub fn family_drift(scripts_dir: &Path, family: &Family) -> Result<FamilyDrift, String> {
+    let variants = family
+        .variants
+        .iter()
+        .map(|name| Variant::load(scripts_dir, name))
+        .collect::<Result<Vec<_>, String>>()?;
+    let (reference, others) = variants
+        .split_first()
+        .ok_or_else(|| format!("family {} has no variants", family.name))?;
+
+    let lines: Vec<String> = others
+        .iter()
+        .flat_map(|variant| variant.drift(reference, family.subset))
+        .collect();
+    let mut drift = FamilyDrift::default();
+    for line in lines.iter() {
+        match family.allow.contains(line) {
+            true => drift.allowed.push(line.clone()),
+            false => drift.unintended.push(line.clone()),
+        }
+    }
+    drift.stale = family
+        .allow
+        .iter()
+        .filter(|line| !lines.contains(line))
+        .cloned()
+        .collect();
+    Ok(drift)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn variant(name: &str, raw: &str, excluded: &[&str]) -> Variant {
+        let coverage: Coverage = toml::from_str(raw).unwrap();
+        let excluded: Vec<String> = excluded.iter().map(|e| e.to_string()).collect();
+        Variant::new(name, &coverage, &excluded).unwrap()
+    }
+
+    #[test]
+    fn test_drift() {
+        let reference = variant(
+            "a",
+            r#"
+            exchanges = [{ name = "BINANCE" }, { name = "KRAKEN" }, { name = "OKX" }]
+            [tokens]
+            BTC = "111"
+            ETH = "110"
+            ENJ = "100"
+            "#,
+            &[],
+        );
+        let other = variant(
+            "b",
+            r#"
+            exchanges = [{ name = "KRAKEN" }, { name = "BINANCE" }, { name = "HUOBIPRO" }]
+            [tokens]
+            BTC = "111"
+            ETH = "011"
+            ENJ = "010"
+            OHM = "001"
+            "#,
+            &["ENJ"],
+        );
+        assert_eq!(
+            other.drift(&reference, true),
+            vec![
+                "b: token OHM added",
+                "b: exchange OKX missing",
+                "b: exchange HUOBIPRO added",
+                "b: exchange order differs",
+                "b: ETH sources -KRAKEN",
+            ]
+        );
+        assert_eq!(
+            other.drift(&reference, false),
+            vec![
+                "b: token ENJ missing",
+                "b: token OHM added",
+                "b: exchange OKX missing",
+                "b: exchange HUOBIPRO added",
+                "b: exchange order differs",
+                "b: ETH sources -KRAKEN",
+            ]
+        );
+        assert_eq!(reference.drift(&reference, false), Vec::<String>::new());
+
+        let other = variant(
+            "c",
+            r#"
+            exchanges = [{ name = "BINANCE" }, { name = "KRAKEN" }, { name = "OKX" }]
+            [tokens]
+            BTC = "011"
+            ETH = "111"
+            ENJ = "100"
+            "#,
+            &[],
+        );
+        assert_eq!(
+            other.drift(&reference, false),
+            vec!["c: BTC sources -BINANCE", "c: ETH sources +OKX"]
+        );
+    }
+
+    #[test]
+    fn test_checked_in_variants_have_no_unintended_drift() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+        for family in load_families(&root.join(VARIANTS_FILE)).unwrap() {
+            let drift = family_drift(&root.join("scripts"), &family).unwrap();
+            assert!(
+                drift.is_clean(),
+                "{}: unintended {:?}, stale allows {:?}",
+                family.name,
+                drift.unintended,
+                drift.stale
+            );
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Build-script helpers for the oracle scripts in this repository.
+//!
+//! A script that is deployed to several Band networks keeps one source file
+//! and one `profiles/<network>.toml` per network. Its `build.rs` calls
+//! [`emit_network_profile`], which picks the profile named by the
+//! `BAND_NETWORK` environment variable, or the `default_network` of its
+//! Cargo.toml, and renders it into `$OUT_DIR/profile.rs` for the script to
+//! `include!`.
+//!
+//! The exchange tables and symbol registries of the price scripts are
+//! generated too: the `os-gen` binary renders a script's `coverage.toml` into
+//! `src/table.rs`, see [`generate_table`]. The `os-drift` binary reports how
+//! the variants of a script on different networks differ, see [`drift`].
+
+pub mod coverage;
+pub mod drift;
+pub mod profile;
+
+pub use coverage::{generate_table, Coverage};
+pub use profile::{
+    available_networks, default_network, emit_network_profile, Profile, VrfProvider,
+};
@@ -0,0 +1,538 @@
+use crate::coverage::{Coverage, COVERAGE_FILE};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Environment variable naming the network profile to build against.
+pub const NETWORK_ENV: &str = "BAND_NETWORK";
+
+/// Deployment settings of one oracle script on one Band network.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Data source ID of every API source exchange, keyed by exchange name.
+    #[serde(default)]
+    pub data_sources: BTreeMap<String, i64>,
+    /// Data source ID of the CCXT data source, for scripts that use it.
+    pub ccxt_data_source_id: Option<i64>,
+    /// Tokens of the script's table that are not served on this network.
+    #[serde(default)]
+    pub excluded_tokens: Vec<String>,
+    /// Tokens this network prices from other exchanges than the script's
+    /// coverage.toml says, in the form of its `tokens`.
+    #[serde(default)]
+    pub coverage: toml::Table,
+    /// VRF providers, in the order used for random provider selection.
+    #[serde(default)]
+    pub vrf: Vec<VrfProvider>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VrfProvider {
+    pub name: String,
+    pub data_source_id: i64,
+    /// Hex-encoded public key, needed by scripts that verify proofs on chain.
+    pub pubkey: Option<String>,
+}
+
+impl Profile {
+    pub fn load(path: &Path) -> Result<Profile, String> {
+        let raw = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        toml::from_str(&raw).map_err(|e| e.to_string())
+    }
+
+    /// Checks that the profile only names tokens of the script's coverage
+    /// table and gives data source IDs to its API source exchanges alone, so
+    /// a misspelt name fails the build rather than being ignored.
+    pub fn check(&self, coverage: &Coverage) -> Result<(), String> {
+        for token in self.excluded_tokens.iter() {
+            if !coverage.tokens.contains_key(token) {
+                return Err(format!(
+                    "excluded token `{}` is not in {}",
+                    token, COVERAGE_FILE
+                ));
+            }
+        }
+        for exchange in self.data_sources.keys() {
+            if !coverage
+                .exchanges
+                .iter()
+                .any(|e| e.api && &e.name == exchange)
+            {
+                return Err(format!(
+                    "data source of `{}`, which is not an API source exchange of {}",
+                    exchange, COVERAGE_FILE
+                ));
+            }
+        }
+        coverage.with_overrides(&self.coverage).map(|_| ())
+    }
+
+    /// Renders the profile as Rust items for the script to `include!`.
+    ///
+    /// Price items are emitted when the profile has `data_sources`, VRF items
+    /// when it has `vrf` providers, so a script built against a profile of
+    /// the wrong shape fails to compile. A price script's profile is checked
+    /// against its `coverage`, which the `coverage` overrides are rendered
+    /// from.
+    pub fn render(&self, network: &str, coverage: Option<&Coverage>) -> Result<String, String> {
+        if self.data_sources.is_empty() && self.vrf.is_empty() {
+            return Err("profile defines neither data_sources nor vrf providers".into());
+        }
+        let overridden = match coverage {
+            Some(coverage) => {
+                self.check(coverage)?;
+                Some(coverage.with_overrides(&self.coverage)?)
+            }
+            None if !self.coverage.is_empty() => {
+                return Err(format!("coverage overrides without a {}", COVERAGE_FILE));
+            }
+            None => None,
+        };
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "// Generated from the `{}` network profile. Do not edit.",
+            network
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "pub const NETWORK: &str = {:?};", network).unwrap();
+
+        if !self.data_sources.is_empty() {
+            writeln!(out).unwrap();
+            writeln!(
+                out,
+                "pub fn data_source_id(exchange: &str) -> Option<i64> {{"
+            )
+            .unwrap();
+            writeln!(out, "    match exchange {{").unwrap();
+            for (exchange, id) in self.data_sources.iter() {
+                writeln!(out, "        {:?} => Some({}),", exchange, id).unwrap();
+            }
+            writeln!(out, "        _ => None,").unwrap();
+            writeln!(out, "    }}").unwrap();
+            writeln!(out, "}}").unwrap();
+            if let Some(id) = self.ccxt_data_source_id {
+                writeln!(out).unwrap();
+                writeln!(out, "pub const CCXT_DATA_SOURCE_ID: i64 = {};", id).unwrap();
+            }
+            writeln!(out).unwrap();
+            writeln!(out, "pub const EXCLUDED_TOKENS: &[&str] = &[").unwrap();
+            for token in self.excluded_tokens.iter() {
+                writeln!(out, "    {:?},", token).unwrap();
+            }
+            writeln!(out, "];").unwrap();
+            writeln!(out).unwrap();
+            match overridden.as_ref() {
+                Some(overridden) if !self.coverage.is_empty() => {
+                    writeln!(out, "use super::Exchange;").unwrap();
+                    writeln!(out).unwrap();
+                    render_coverage(&mut out, overridden, &self.coverage)?;
+                }
+                _ => writeln!(
+                    out,
+                    "pub const COVERAGE: &[(&str, os_common::SymbolInfo)] = &[];"
+                )
+                .unwrap(),
+            }
+        }
+
+        if !self.vrf.is_empty() {
+            let with_pubkey = self.vrf.iter().filter(|p| p.pubkey.is_some()).count();
+            if with_pubkey != 0 && with_pubkey != self.vrf.len() {
+                return Err("either every vrf provider has a pubkey or none does".into());
+            }
+            let with_pubkey = with_pubkey != 0;
+            for provider in self.vrf.iter() {
+                if let Some(pubkey) = provider.pubkey.as_ref() {
+                    check_hex(pubkey)
+                        .map_err(|err| format!("pubkey of `{}`: {}", provider.name, err))?;
+                }
+            }
+
+            writeln!(out).unwrap();
+            writeln!(out, "pub struct VrfProvider {{").unwrap();
+            writeln!(out, "    pub name: &'static str,").unwrap();
+            writeln!(out, "    pub data_source_id: i64,").unwrap();
+            if with_pubkey {
+                writeln!(out, "    pub pubkey: &'static str,").unwrap();
+            }
+            writeln!(out, "}}").unwrap();
+            writeln!(out).unwrap();
+            writeln!(out, "pub const VRF_PROVIDERS: &[VrfProvider] = &[").unwrap();
+            for provider in self.vrf.iter() {
+                writeln!(out, "    VrfProvider {{").unwrap();
+                writeln!(out, "        name: {:?},", provider.name).unwrap();
+                writeln!(out, "        data_source_id: {},", provider.data_source_id).unwrap();
+                if let Some(pubkey) = provider.pubkey.as_ref() {
+                    writeln!(out, "        pubkey: {:?},", pubkey).unwrap();
+                }
+                writeln!(out, "    }},").unwrap();
+            }
+            writeln!(out, "];").unwrap();
+        }
+
+        Ok(out)
+    }
+}
+
+/// Renders the `COVERAGE` overrides of `symbols` as `exchanges!` sets of the
+/// script's `Exchange` enum, taking their rows from the overridden table.
+fn render_coverage(
+    out: &mut String,
+    overridden: &Coverage,
+    symbols: &toml::Table,
+) -> Result<(), String> {
+    writeln!(
+        out,
+        "pub const COVERAGE: &[(&str, os_common::SymbolInfo)] = &["
+    )
+    .unwrap();
+    for (symbol, coverage, min_sources) in overridden.token_quorums()? {
+        if !symbols.contains_key(symbol) {
+            continue;
+        }
+        let covering: Vec<&str> = coverage
+            .chars()
+            .zip(overridden.exchanges.iter())
+            .filter(|(bit, _)| *bit == '1')
+            .map(|(_, exchange)| exchange.name.as_str())
+            .collect();
+        writeln!(
+            out,
+            "    ({:?}, os_common::SymbolInfo {{ exchanges: os_common::exchanges![{}], min_sources: {} }}),",
+            symbol,
+            covering.join(", "),
+            min_sources
+        )
+        .unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    Ok(())
+}
+
+/// Checks that `value` is a non-empty, even-length string of hex digits.
+fn check_hex(value: &str) -> Result<(), String> {
+    if value.is_empty() || value.len() % 2 == 1 {
+        return Err(format!("{:?} is not a whole number of hex bytes", value));
+    }
+    match value.bytes().all(|b| b.is_ascii_hexdigit()) {
+        true => Ok(()),
+        false => Err(format!("{:?} is not hex", value)),
+    }
+}
+
+/// Renders `profiles/<network>.toml` of the crate being built into
+/// `$OUT_DIR/profile.rs`. The network is read from `BAND_NETWORK`, falling
+/// back to the crate's [`default_network`]. Meant to be called from a
+/// `build.rs`.
+pub fn emit_network_profile() {
+    println!("cargo:rerun-if-env-changed={}", NETWORK_ENV);
+    let script_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let profiles_dir = script_dir.join("profiles");
+    println!("cargo:rerun-if-changed={}", profiles_dir.display());
+    println!(
+        "cargo:rerun-if-changed={}",
+        script_dir.join("Cargo.toml").display()
+    );
+
+    let network = match env::var(NETWORK_ENV) {
+        Ok(network) => network,
+        Err(_) => default_network(&script_dir).unwrap_or_else(|err| panic!("{}", err)),
+    };
+    let path = profiles_dir.join(format!("{}.toml", network));
+    if !path.exists() {
+        panic!(
+            "no `{}` network profile, available profiles: {}",
+            network,
+            available_networks(&profiles_dir).join(", ")
+        );
+    }
+
+    // Price scripts have a coverage table the profile is checked against
+    let coverage_path = script_dir.join(COVERAGE_FILE);
+    println!("cargo:rerun-if-changed={}", coverage_path.display());
+    let coverage = match coverage_path.exists() {
+        true => Some(
+            Coverage::load(&coverage_path)
+                .unwrap_or_else(|err| panic!("{}: {}", coverage_path.display(), err)),
+        ),
+        false => None,
+    };
+
+    let rendered = Profile::load(&path)
+        .and_then(|profile| profile.render(&network, coverage.as_ref()))
+        .unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("profile.rs"), rendered).unwrap();
+}
+
+/// The network profile the script in `script_dir` is built against when
+/// `BAND_NETWORK` is not set, declared in its Cargo.toml:
+///
+/// ```toml
+/// [package.metadata.band]
+/// default_network = "mainnet"
+/// ```
+///
+/// Both [`emit_network_profile`] and the tools that explain a built script
+/// read it, so they agree on the network.
+pub fn default_network(script_dir: &Path) -> Result<String, String> {
+    let path = script_dir.join("Cargo.toml");
+    let raw = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let manifest: toml::Table =
+        toml::from_str(&raw).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let network = manifest
+        .get("package")
+        .and_then(|package| package.get("metadata"))
+        .and_then(|metadata| metadata.get("band"))
+        .and_then(|band| band.get("default_network"))
+        .and_then(|network| network.as_str());
+    match network {
+        Some(network) => Ok(network.to_string()),
+        None => Err(format!(
+            "{}: no `default_network` string in [package.metadata.band]",
+            path.display()
+        )),
+    }
+}
+
+/// Names of the network profiles in `profiles_dir`, sorted.
+pub fn available_networks(profiles_dir: &Path) -> Vec<String> {
+    let mut networks: Vec<String> = fs::read_dir(profiles_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter_map(|name| name.strip_suffix(".toml").map(|n| n.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    networks.sort();
+    networks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(raw: &str) -> Profile {
+        toml::from_str(raw).unwrap()
+    }
+
+    #[test]
+    fn test_render_price_profile() {
+        let profile = parse(
+            r#"
+            ccxt_data_source_id = 3
+            excluded_tokens = ["ENJ"]
+
+            [data_sources]
+            COINGECKO = 74
+            BINANCE = 54
+            "#,
+        );
+        assert_eq!(
+            profile.render("testnet", None).unwrap(),
+            r#"// Generated from the `testnet` network profile. Do not edit.
+
+pub const NETWORK: &str = "testnet";
+
+pub fn data_source_id(exchange: &str) -> Option<i64> {
+    match exchange {
+        "BINANCE" => Some(54),
+        "COINGECKO" => Some(74),
+        _ => None,
+    }
+}
+
+pub const CCXT_DATA_SOURCE_ID: i64 = 3;
+
+pub const EXCLUDED_TOKENS: &[&str] = &[
+    "ENJ",
+];
+
+pub const COVERAGE: &[(&str, os_common::SymbolInfo)] = &[];
+"#
+        );
+    }
+
+    fn coverage() -> Coverage {
+        toml::from_str(
+            r#"
+            exchanges = [
+                { name = "COINGECKO", api = true },
+                { name = "BINANCE" },
+                { name = "KRAKEN" },
+            ]
+
+            [tokens]
+            BTC = "111"
+            ENJ = "110"
+            OHM = "100"
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_render_coverage_overrides() {
+        let profile = parse(
+            r#"
+            ccxt_data_source_id = 3
+
+            [data_sources]
+            COINGECKO = 74
+
+            [coverage]
+            OHM = "011"
+            ENJ = { coverage = "111", min_sources = 2 }
+            "#,
+        );
+        let rendered = profile.render("testnet", Some(&coverage())).unwrap();
+        assert!(rendered.ends_with(
+            r#"
+use super::Exchange;
+
+pub const COVERAGE: &[(&str, os_common::SymbolInfo)] = &[
+    ("ENJ", os_common::SymbolInfo { exchanges: os_common::exchanges![COINGECKO, BINANCE, KRAKEN], min_sources: 2 }),
+    ("OHM", os_common::SymbolInfo { exchanges: os_common::exchanges![BINANCE, KRAKEN], min_sources: 1 }),
+];
+"#
+        ));
+        assert!(profile.render("testnet", None).is_err());
+    }
+
+    #[test]
+    fn test_render_rejects_names_missing_from_coverage() {
+        for raw in [
+            "excluded_tokens = [\"DOGE\"]\n[data_sources]\nCOINGECKO = 74",
+            "[data_sources]\nCOINGEKO = 74",
+            // BINANCE is queried through CCXT
+            "[data_sources]\nBINANCE = 74",
+            "[data_sources]\nCOINGECKO = 74\n[coverage]\nDOGE = \"100\"",
+            "[data_sources]\nCOINGECKO = 74\n[coverage]\nOHM = \"10\"",
+        ] {
+            assert!(
+                parse(raw).render("testnet", Some(&coverage())).is_err(),
+                "{}",
+                raw
+            );
+        }
+        assert!(
+            parse("excluded_tokens = [\"ENJ\"]\n[data_sources]\nCOINGECKO = 74")
+                .render("testnet", Some(&coverage()))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_render_vrf_profile() {
+        let profile = parse(
+            r#"
+            [[vrf]]
+            name = "VRF1"
+            data_source_id = 103
+            pubkey = "99812aab"
+
+            [[vrf]]
+            name = "VRF2"
+            data_source_id = 104
+            pubkey = "8deed235"
+            "#,
+        );
+        let rendered = profile.render("mainnet", None).unwrap();
+        assert!(rendered.contains("    pub pubkey: &'static str,\n"));
+        assert!(rendered.contains(
+            "    VrfProvider {\n        name: \"VRF2\",\n        data_source_id: 104,\n        pubkey: \"8deed235\",\n    },\n"
+        ));
+        assert!(!rendered.contains("data_source_id(exchange"));
+    }
+
+    #[test]
+    fn test_render_vrf_profile_without_pubkeys() {
+        let profile = parse(
+            r#"
+            [[vrf]]
+            name = "VRF1"
+            data_source_id = 94
+            "#,
+        );
+        assert!(!profile.render("mainnet", None).unwrap().contains("pubkey"));
+    }
+
+    #[test]
+    fn test_render_rejects_mixed_pubkeys() {
+        let profile = parse(
+            r#"
+            [[vrf]]
+            name = "VRF1"
+            data_source_id = 94
+            pubkey = "99812aab"
+
+            [[vrf]]
+            name = "VRF2"
+            data_source_id = 95
+            "#,
+        );
+        assert!(profile.render("mainnet", None).is_err());
+    }
+
+    #[test]
+    fn test_render_rejects_non_hex_pubkeys() {
+        for pubkey in ["", "99812aa", "0x99812aab", "99812aaz"] {
+            let profile = parse(&format!(
+                r#"
+                [[vrf]]
+                name = "VRF1"
+                data_source_id = 94
+                pubkey = {:?}
+                "#,
+                pubkey
+            ));
+            assert!(profile.render("mainnet", None).is_err(), "{:?}", pubkey);
+        }
+    }
+
+    #[test]
+    fn test_render_rejects_empty_profile() {
+        assert!(Profile::default().render("mainnet", None).is_err());
+    }
+
+    #[test]
+    fn test_default_network() {
+        let scripts_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../scripts");
+        assert_eq!(
+            default_network(&scripts_dir.join("linear_os")),
+            Ok("mainnet".into())
+        );
+        assert_eq!(
+            default_network(&scripts_dir.join("vrf_v3")),
+            Ok("v3-testnet".into())
+        );
+        assert!(default_network(&scripts_dir.join("hello_world_os")).is_err());
+        // Every script with profiles builds against one of them by default
+        for entry in fs::read_dir(&scripts_dir).unwrap() {
+            let script_dir = entry.unwrap().path();
+            let profiles_dir = script_dir.join("profiles");
+            if profiles_dir.exists() {
+                let network = default_network(&script_dir).unwrap();
+                assert!(
+                    available_networks(&profiles_dir).contains(&network),
+                    "{}: {}",
+                    script_dir.display(),
+                    network
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_field_is_rejected() {
+        assert!(toml::from_str::<Profile>("data_source = 3").is_err());
+    }
+}
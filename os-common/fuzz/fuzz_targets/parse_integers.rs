@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use os_common::report::parse_integers;
+
+fuzz_target!(|input: (u8, &str)| {
+    let (count, report) = input;
+    if let Some(values) = parse_integers(report, count as usize) {
+        let fields: Vec<&str> = report.split_whitespace().collect();
+        assert_eq!(values.len(), count as usize);
+        assert_eq!(values.len(), fields.len());
+        for (value, field) in values.iter().zip(fields) {
+            assert!(field.bytes().all(|b| b.is_ascii_digit()));
+            assert_eq!(Ok(*value), field.parse::<u64>());
+        }
+    }
+});
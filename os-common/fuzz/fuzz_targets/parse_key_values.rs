@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use os_common::report::parse_key_values;
+
+fuzz_target!(|report: &str| {
+    if let Some(pairs) = parse_key_values(report) {
+        let fields: Vec<&str> = report.split(',').collect();
+        assert_eq!(pairs.len(), fields.len());
+        for ((key, value), field) in pairs.iter().zip(fields) {
+            let (k, v) = field.split_once(':').unwrap();
+            assert!(!key.is_empty());
+            assert_eq!(key, k);
+            assert!(v.bytes().all(|b| b.is_ascii_digit()));
+            assert_eq!(Ok(*value), v.parse::<u64>());
+        }
+    }
+});
@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use os_common::report::{parse_prices, MISSING_PRICE};
+use os_common::Decimal;
+
+fuzz_target!(|input: (u8, &str)| {
+    let (count, report) = input;
+    if let Some(prices) = parse_prices(report, count as usize) {
+        let fields: Vec<&str> = report.split(',').collect();
+        assert_eq!(prices.len(), count as usize);
+        assert_eq!(prices.len(), fields.len());
+        for (px, field) in prices.iter().zip(fields) {
+            match px {
+                Some(px) => {
+                    assert!(*px > Decimal::ZERO);
+                    assert_eq!(Ok(*px), field.parse::<Decimal>());
+                    assert_eq!(Ok(*px), px.to_string().parse::<Decimal>());
+                }
+                None => {
+                    assert!(field == MISSING_PRICE || field.parse::<Decimal>() == Ok(Decimal::ZERO))
+                }
+            }
+        }
+    }
+});
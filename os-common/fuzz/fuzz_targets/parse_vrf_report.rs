@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use os_common::report::{parse_vrf_report, VRF_REPORT_LEN};
+
+fuzz_target!(|report: &str| {
+    if let Some((proof, result)) = parse_vrf_report(report) {
+        assert_eq!(report.len(), VRF_REPORT_LEN);
+        assert_eq!(proof.len(), 80);
+        assert_eq!(result.len(), 64);
+        let encoded = hex::encode(proof) + &hex::encode(result);
+        assert_eq!(encoded, report.to_ascii_lowercase());
+    }
+});
@@ -0,0 +1,104 @@
+/// The exchanges that cover a token, one bit per exchange ID.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExchangeSet(u64);
+
+impl ExchangeSet {
+    /// Exchange IDs must be below this, as they index bits of a `u64`.
+    pub const CAPACITY: u64 = u64::BITS as u64;
+
+    /// Builds the set from exchange IDs. Built through [`exchanges!`], this
+    /// runs at compile time and an out of range or repeated ID fails the
+    /// build.
+    pub const fn from_ids(ids: &[u64]) -> ExchangeSet {
+        let mut bits = 0u64;
+        let mut i = 0;
+        while i < ids.len() {
+            let id = ids[i];
+            assert!(id < Self::CAPACITY, "exchange ID out of range");
+            assert!(bits & (1 << id) == 0, "exchange listed twice");
+            bits |= 1 << id;
+            i += 1;
+        }
+        ExchangeSet(bits)
+    }
+
+    pub const fn contains(self, id: u64) -> bool {
+        id < Self::CAPACITY && self.0 & (1 << id) != 0
+    }
+
+    pub const fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Adds an exchange ID below [`ExchangeSet::CAPACITY`].
+    pub fn insert(&mut self, id: u64) {
+        assert!(id < Self::CAPACITY, "exchange ID out of range");
+        self.0 |= 1 << id;
+    }
+
+    /// The set as a bitmap, bit `id` standing for exchange `id`.
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the exchange IDs in the set in ascending order.
+    pub fn ids(self) -> impl Iterator<Item = u64> {
+        (0..Self::CAPACITY).filter(move |&id| self.contains(id))
+    }
+}
+
+/// Builds a const [`ExchangeSet`] from variants of the `Exchange` enum in
+/// scope, e.g. `exchanges![BINANCE, KRAKEN]`. A name that is not a variant
+/// is a compile error.
+#[macro_export]
+macro_rules! exchanges {
+    ($($exchange:ident),* $(,)?) => {
+        const { $crate::ExchangeSet::from_ids(&[$(Exchange::$exchange as u64),*]) }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    enum Exchange {
+        COINGECKO = 0,
+        BINANCE = 1,
+        KRAKEN = 2,
+    }
+
+    #[test]
+    fn test_exchanges_macro() {
+        let set = exchanges![COINGECKO, KRAKEN];
+        assert!(set.contains(0));
+        assert!(!set.contains(1));
+        assert!(set.contains(2));
+        assert!(!set.contains(ExchangeSet::CAPACITY));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.ids().collect::<Vec<_>>(), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_empty_set() {
+        let set = exchanges![];
+        assert!(set.is_empty());
+        assert_eq!(set.ids().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "exchange listed twice")]
+    fn test_repeated_exchange() {
+        ExchangeSet::from_ids(&[1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "exchange ID out of range")]
+    fn test_exchange_out_of_range() {
+        ExchangeSet::from_ids(&[ExchangeSet::CAPACITY]);
+    }
+}
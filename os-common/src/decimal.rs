@@ -0,0 +1,228 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A non-negative decimal with [`Decimal::DIGITS`] fractional digits, held
+/// as an integer count of its smallest unit.
+///
+/// Prices are parsed from their report text straight into a `Decimal` and
+/// combined and scaled with integer arithmetic only, so a rate is exact up
+/// to `u128::MAX` and the same on every validator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal(u128);
+
+impl Decimal {
+    /// Fractional digits kept. Digits past them are dropped when parsing.
+    pub const DIGITS: u32 = 18;
+    const ONE: u128 = 10u128.pow(Decimal::DIGITS);
+
+    pub const ZERO: Decimal = Decimal(0);
+    pub const MAX: Decimal = Decimal(u128::MAX);
+
+    pub const fn from_integer(value: u64) -> Decimal {
+        Decimal(value as u128 * Decimal::ONE)
+    }
+
+    /// The value of `units` times `10^-DIGITS`.
+    pub const fn from_units(units: u128) -> Decimal {
+        Decimal(units)
+    }
+
+    /// The value in units of `10^-DIGITS`.
+    pub const fn units(self) -> u128 {
+        self.0
+    }
+
+    /// The value times `multiplier`, rounded down, or `None` when it does
+    /// not fit in a `u128`.
+    pub fn scale(self, multiplier: u64) -> Option<u128> {
+        let multiplier = multiplier as u128;
+        let whole = (self.0 / Decimal::ONE).checked_mul(multiplier)?;
+        // Below 10^18 * 2^64, so it cannot overflow
+        let fraction = self.0 % Decimal::ONE * multiplier / Decimal::ONE;
+        whole.checked_add(fraction)
+    }
+
+    /// The product, rounded down, or [`Decimal::MAX`] when it overflows.
+    pub fn saturating_mul(self, other: Decimal) -> Decimal {
+        let (a, b) = (self.0 / Decimal::ONE, self.0 % Decimal::ONE);
+        let (c, d) = (other.0 / Decimal::ONE, other.0 % Decimal::ONE);
+        let units = a
+            .saturating_mul(c)
+            .saturating_mul(Decimal::ONE)
+            .saturating_add(a.saturating_mul(d))
+            .saturating_add(b.saturating_mul(c))
+            .saturating_add(b * d / Decimal::ONE);
+        Decimal(units)
+    }
+
+    pub fn abs_diff(self, other: Decimal) -> Decimal {
+        Decimal(self.0.abs_diff(other.0))
+    }
+
+    /// An approximation, e.g. for statistics that need not be exact.
+    pub fn to_f64(self) -> f64 {
+        (self.0 / Decimal::ONE) as f64 + (self.0 % Decimal::ONE) as f64 / Decimal::ONE as f64
+    }
+}
+
+/// Parses decimal digits with an optional point and exponent, e.g. `1.5`,
+/// `.5`, `100.` or `1e-05`. Signs other than the exponent's, `inf` and `NaN`
+/// are rejected, as is a value too large to hold.
+impl FromStr for Decimal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Decimal, String> {
+        let invalid = || format!("invalid decimal {:?}", s);
+        let (mantissa, exponent) = match s.find(['e', 'E']) {
+            Some(at) => {
+                let exponent = &s[at + 1..];
+                let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                (&s[..at], exponent.parse::<i32>().map_err(|_| invalid())?)
+            }
+            None => (s, 0),
+        };
+        let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if (whole.is_empty() && fraction.is_empty())
+            || !whole
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        // The digits read as an integer, times 10^shift
+        let digits = whole.bytes().chain(fraction.bytes());
+        let shift = Decimal::DIGITS as i64 + exponent as i64 - fraction.len() as i64;
+        let len = whole.len() + fraction.len();
+        let kept = match shift < 0 {
+            true => len.saturating_sub(shift.unsigned_abs() as usize),
+            false => len,
+        };
+        let mut units = 0u128;
+        for digit in digits.take(kept) {
+            units = units
+                .checked_mul(10)
+                .and_then(|units| units.checked_add((digit - b'0') as u128))
+                .ok_or_else(invalid)?;
+        }
+        if units > 0 && shift > 0 {
+            units = u32::try_from(shift)
+                .ok()
+                .and_then(|shift| 10u128.checked_pow(shift))
+                .and_then(|scale| units.checked_mul(scale))
+                .ok_or_else(invalid)?;
+        }
+        Ok(Decimal(units))
+    }
+}
+
+/// Writes the shortest form that parses back to the same value, e.g. `1.5`.
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (whole, fraction) = (self.0 / Decimal::ONE, self.0 % Decimal::ONE);
+        match fraction {
+            0 => write!(f, "{}", whole),
+            _ => {
+                let digits = format!("{:018}", fraction);
+                write!(f, "{}.{}", whole, digits.trim_end_matches('0'))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(decimal("1.5"), Decimal(15 * 10u128.pow(17)));
+        assert_eq!(decimal("1.5"), decimal("1.50"));
+        assert_eq!(decimal(".5"), decimal("0.5"));
+        assert_eq!(decimal("100."), Decimal::from_integer(100));
+        assert_eq!(decimal("1e-05"), decimal("0.00001"));
+        assert_eq!(decimal("1.5E+3"), Decimal::from_integer(1500));
+        assert_eq!(decimal("0.0000000000000000019"), Decimal(1));
+        assert_eq!(decimal("0e400"), Decimal::ZERO);
+        assert_eq!(decimal("00012"), Decimal::from_integer(12));
+        assert_eq!(
+            decimal("340282366920938463463.374607431768211455"),
+            Decimal::MAX
+        );
+        for s in [
+            "",
+            ".",
+            "-1",
+            "+1",
+            "1,5",
+            "1.5.1",
+            " 1",
+            "inf",
+            "NaN",
+            "1e",
+            "1e+",
+            "e5",
+            "1e400",
+            "340282366920938463464",
+        ] {
+            assert!(s.parse::<Decimal>().is_err(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        for s in ["0", "1.5", "100", "0.000000000000000001", "67234.12345"] {
+            assert_eq!(decimal(s).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_scale() {
+        // 2^53 + 1 is the first integer an f64 cannot hold
+        assert_eq!(decimal("9007199254740993").scale(1), Some(9007199254740993));
+        assert_eq!(
+            decimal("67234.123456789012345678").scale(100_000_000_000_000),
+            Some(6723412345678901234)
+        );
+        assert_eq!(
+            decimal("0.000012345678901234").scale(1_000_000_000_000_000_000),
+            Some(12345678901234)
+        );
+        assert_eq!(decimal("0.999").scale(100), Some(99));
+        // A BTC rate at 1e18 precision, past u64::MAX
+        assert_eq!(
+            decimal("67234.1").scale(1_000_000_000_000_000_000),
+            Some(67_234_100_000_000_000_000_000)
+        );
+        assert_eq!(
+            decimal("340282366920938463463").scale(1),
+            Some(340282366920938463463)
+        );
+        assert_eq!(Decimal::MAX.scale(1), Some(u128::MAX / 10u128.pow(18)));
+        assert_eq!(Decimal::MAX.scale(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_saturating_mul() {
+        assert_eq!(
+            decimal("1.5").saturating_mul(decimal("2.5")),
+            decimal("3.75")
+        );
+        assert_eq!(
+            decimal("0.000000001").saturating_mul(decimal("0.000000001")),
+            Decimal(1)
+        );
+        assert_eq!(
+            Decimal::MAX.saturating_mul(Decimal::from_integer(3)),
+            Decimal::MAX
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt;
+
+/// Why a script refused or failed a request.
+///
+/// Every variant has a numeric code that never changes meaning, so a relayer
+/// can react to the failure without matching on message text. Codes are
+/// grouped by hundreds: 1xx bad input, 2xx script configuration, 3xx
+/// reported data, 4xx VRF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptError {
+    /// The calldata does not decode, or breaks the script's input rules.
+    InvalidInput(String),
+    /// A requested symbol is not served by the script.
+    UnknownSymbol(String),
+    /// An external ID does not name any of the script's exchanges.
+    UnknownExchange(u64),
+    /// An API source exchange has no data source ID on this network.
+    MissingDataSource(String),
+    /// A provider index does not name any of the script's VRF providers.
+    UnknownVrfProvider(usize),
+    /// The public key of the named VRF provider is not valid hex.
+    InvalidVrfPubkey(String),
+    /// No validator returned a usable report for the external ID.
+    NoReports(i64),
+    /// A report for the external ID could not be parsed.
+    MalformedReport(i64),
+    /// Validators reported for the external ID, but no value was reported
+    /// by a majority of them.
+    NoMajority(i64),
+    /// No exchange returned a price for the symbol, or its rate is 0.
+    NoPrice(String),
+    /// Fewer exchanges than the symbol needs returned a price for it.
+    TooFewSources {
+        symbol: String,
+        sources: usize,
+        min_sources: usize,
+    },
+    /// The rate of the symbol at the requested multiplier does not fit in
+    /// the integer the output encodes rates as, e.g. a `u64`.
+    RateOverflow(String),
+    /// The VRF proof does not verify against the provider's public key.
+    InvalidVrfProof,
+    /// The host failed to run VRF verification, with its error code.
+    VrfVerification(u32),
+}
+
+impl ScriptError {
+    /// Prefix of [`ScriptError::report`].
+    pub const REPORT_PREFIX: &'static str = "script error";
+    /// Longest report, in bytes. Well below the smallest span size a chain
+    /// runs with, so the host always accepts it.
+    pub const MAX_REPORT_LEN: usize = 256;
+
+    pub fn code(&self) -> u16 {
+        match self {
+            ScriptError::InvalidInput(_) => 100,
+            ScriptError::UnknownSymbol(_) => 101,
+            ScriptError::UnknownExchange(_) => 200,
+            ScriptError::MissingDataSource(_) => 201,
+            ScriptError::UnknownVrfProvider(_) => 202,
+            ScriptError::InvalidVrfPubkey(_) => 203,
+            ScriptError::NoReports(_) => 300,
+            ScriptError::MalformedReport(_) => 301,
+            ScriptError::NoPrice(_) => 302,
+            ScriptError::TooFewSources { .. } => 303,
+            ScriptError::RateOverflow(_) => 304,
+            ScriptError::NoMajority(_) => 305,
+            ScriptError::InvalidVrfProof => 400,
+            ScriptError::VrfVerification(_) => 401,
+        }
+    }
+
+    /// The failure as `script error <code>: <message>`, cut to
+    /// [`ScriptError::MAX_REPORT_LEN`] bytes.
+    pub fn report(&self) -> String {
+        let mut report = format!("{} {}: {}", Self::REPORT_PREFIX, self.code(), self);
+        if report.len() > Self::MAX_REPORT_LEN {
+            let mut end = Self::MAX_REPORT_LEN;
+            while !report.is_char_boundary(end) {
+                end -= 1;
+            }
+            report.truncate(end);
+        }
+        report
+    }
+
+    /// Fails the request with [`ScriptError::report`] as the panic message.
+    /// The execute entry point hands the report to the host before calling
+    /// it, see [`execute_entry_point`](crate::execute_entry_point).
+    pub fn abort(self) -> ! {
+        panic!("{}", self.report())
+    }
+
+    /// Recovers the code from a [`ScriptError::report`], e.g. the return
+    /// data of a failed phase or the panic message of [`ScriptError::abort`].
+    pub fn code_from_report(report: &str) -> Option<u16> {
+        let rest = report
+            .strip_prefix(Self::REPORT_PREFIX)?
+            .strip_prefix(' ')?;
+        rest.split(':').next()?.parse().ok()
+    }
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScriptError::InvalidInput(reason) => write!(f, "invalid input: {}", reason),
+            ScriptError::UnknownSymbol(symbol) => write!(f, "unknown symbol {}", symbol),
+            ScriptError::UnknownExchange(id) => write!(f, "unknown exchange ID {}", id),
+            ScriptError::MissingDataSource(exchange) => {
+                write!(f, "no data source for exchange {}", exchange)
+            }
+            ScriptError::UnknownVrfProvider(index) => {
+                write!(f, "unknown VRF provider index {}", index)
+            }
+            ScriptError::InvalidVrfPubkey(provider) => {
+                write!(f, "public key of VRF provider {} is not hex", provider)
+            }
+            ScriptError::NoReports(eid) => write!(f, "no usable reports for external ID {}", eid),
+            ScriptError::MalformedReport(eid) => {
+                write!(f, "malformed report for external ID {}", eid)
+            }
+            ScriptError::NoMajority(eid) => {
+                write!(f, "no majority among the reports for external ID {}", eid)
+            }
+            ScriptError::NoPrice(symbol) => write!(f, "no price for symbol {}", symbol),
+            ScriptError::TooFewSources {
+                symbol,
+                sources,
+                min_sources,
+            } => write!(
+                f,
+                "{} of the {} exchanges symbol {} needs returned a price",
+                sources, min_sources, symbol
+            ),
+            ScriptError::RateOverflow(symbol) => {
+                write!(f, "rate of symbol {} does not fit in the output", symbol)
+            }
+            ScriptError::InvalidVrfProof => write!(f, "VRF proof is invalid"),
+            ScriptError::VrfVerification(code) => {
+                write!(f, "VRF verification failed with host code {}", code)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+/// Entry point for a `fn(Input) -> Result<(), ScriptError>` prepare function.
+/// Calldata that does not decode fails with [`ScriptError::InvalidInput`].
+///
+/// On failure the script aborts with [`ScriptError::report`] as the panic
+/// message, which the mock host reads the code from. Unlike execute, it
+/// leaves no return data, as the chain refuses it in prepare.
+///
+/// `prepare_entry_point!(f as name)` exports the entry point as `name`, e.g.
+/// for tests to run the prepare function of a build variant.
+#[macro_export]
+macro_rules! prepare_entry_point {
+    ($name:ident) => {
+        $crate::prepare_entry_point!($name as prepare);
+    };
+    ($name:ident as $export:ident) => {
+        #[no_mangle]
+        pub fn $export() {
+            let result = ::obi::OBIDecode::try_from_slice(&$crate::host::oei::get_calldata())
+                .map_err(|err| $crate::ScriptError::InvalidInput(err.to_string()))
+                .and_then($name);
+            if let Err(err) = result {
+                err.abort();
+            }
+        }
+    };
+}
+
+/// Entry point for a `fn(Input) -> Result<Output, ScriptError>` execute
+/// function. Calldata that does not decode fails with
+/// [`ScriptError::InvalidInput`].
+///
+/// On failure the script saves [`ScriptError::report`] as return data, then
+/// aborts. The chain keeps no result for a failed request, but replaying it
+/// from the calldata and reports on chain, e.g. with os-run, shows the code.
+///
+/// `execute_entry_point!(f as name)` exports the entry point as `name`, like
+/// [`prepare_entry_point`](crate::prepare_entry_point).
+#[macro_export]
+macro_rules! execute_entry_point {
+    ($name:ident) => {
+        $crate::execute_entry_point!($name as execute);
+    };
+    ($name:ident as $export:ident) => {
+        #[no_mangle]
+        pub fn $export() {
+            let result = ::obi::OBIDecode::try_from_slice(&$crate::host::oei::get_calldata())
+                .map_err(|err| $crate::ScriptError::InvalidInput(err.to_string()))
+                .and_then($name);
+            match result {
+                Ok(output) => $crate::host::oei::save_return_data(
+                    &::obi::OBIEncode::try_to_vec(&output).unwrap(),
+                ),
+                Err(err) => {
+                    $crate::host::oei::save_return_data(err.report().as_bytes());
+                    err.abort()
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_are_stable() {
+        let codes: Vec<u16> = [
+            ScriptError::InvalidInput(String::new()),
+            ScriptError::UnknownSymbol(String::new()),
+            ScriptError::UnknownExchange(0),
+            ScriptError::MissingDataSource(String::new()),
+            ScriptError::UnknownVrfProvider(0),
+            ScriptError::InvalidVrfPubkey(String::new()),
+            ScriptError::NoReports(0),
+            ScriptError::MalformedReport(0),
+            ScriptError::NoPrice(String::new()),
+            ScriptError::TooFewSources {
+                symbol: String::new(),
+                sources: 0,
+                min_sources: 0,
+            },
+            ScriptError::RateOverflow(String::new()),
+            ScriptError::NoMajority(0),
+            ScriptError::InvalidVrfProof,
+            ScriptError::VrfVerification(0),
+        ]
+        .iter()
+        .map(ScriptError::code)
+        .collect();
+        assert_eq!(
+            codes,
+            vec![100, 101, 200, 201, 202, 203, 300, 301, 302, 303, 304, 305, 400, 401]
+        );
+    }
+
+    #[test]
+    fn test_abort_message_round_trip() {
+        let err = ScriptError::UnknownSymbol("DOGE".into());
+        let message = std::panic::catch_unwind(|| err.abort())
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap();
+        assert_eq!(*message, "script error 101: unknown symbol DOGE");
+        assert_eq!(ScriptError::code_from_report(&message), Some(101));
+    }
+
+    #[test]
+    fn test_report_is_cut_to_length() {
+        let report = ScriptError::UnknownSymbol("é".repeat(200)).report();
+        assert!(report.len() <= ScriptError::MAX_REPORT_LEN);
+        assert!(report.len() > ScriptError::MAX_REPORT_LEN - 2);
+        assert_eq!(ScriptError::code_from_report(&report), Some(101));
+    }
+
+    #[test]
+    fn test_code_from_foreign_panic_message() {
+        assert_eq!(ScriptError::code_from_report("index out of bounds"), None);
+    }
+}
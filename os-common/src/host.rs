@@ -0,0 +1,102 @@
+//! One set of host calls for every script, whichever owasm-kit line backs it.
+//!
+//! Scripts import `oei` and `ext` from here instead of from owasm-kit, along
+//! with the entry point macros of [`crate::error`], which resolve
+//! `oei::get_calldata` and `oei::save_return_data` against this module. The
+//! runtime is picked with the `owasm-kit-v3` (default) or `owasm-kit-v1`
+//! feature of this crate; the signatures below are the same under both.
+//! With the `mock` feature, host calls go to the native simulator in
+//! [`mock`] instead, so scripts can be tested under `cargo test`.
+
+#[cfg(feature = "owasm-kit-v3")]
+use owasm_kit as kit;
+#[cfg(all(feature = "owasm-kit-v1", not(feature = "owasm-kit-v3")))]
+use owasm_kit_v1 as kit;
+
+#[cfg(all(feature = "mock", target_arch = "wasm32"))]
+compile_error!("the `mock` feature of os-common is for native tests only");
+
+pub use crate::{execute_entry_point, prepare_entry_point};
+
+pub mod mock;
+#[cfg(feature = "mock")]
+pub mod replay;
+
+pub mod oei {
+    #[cfg(not(feature = "mock"))]
+    use super::kit::oei as backend;
+    #[cfg(feature = "mock")]
+    use super::mock::oei as backend;
+
+    pub use backend::{
+        get_ans_count, get_ask_count, get_calldata, get_execute_time, get_external_data,
+        get_min_count, get_prepare_time, save_return_data,
+    };
+
+    /// Error code returned by [`ecvrf_verify`] on runtimes that cannot
+    /// verify VRF proofs.
+    pub const ECVRF_UNSUPPORTED: u32 = u32::MAX;
+
+    /// Issues a raw request to data source `did` under external ID `eid`.
+    /// Must only be called during the preparation phase.
+    pub fn ask_external_data(eid: i64, did: i64, calldata: &[u8]) {
+        backend::ask_external_data(eid, did, calldata)
+    }
+
+    /// Verifies the ECVRF proof `pi` of `alpha` under public key `y`. `Err`
+    /// carries the host's error code, or [`ECVRF_UNSUPPORTED`].
+    #[cfg(any(feature = "owasm-kit-v3", feature = "mock"))]
+    pub fn ecvrf_verify(y: &[u8], pi: &[u8], alpha: &[u8]) -> Result<bool, u32> {
+        backend::ecvrf_verify(y, pi, alpha)
+    }
+
+    /// Verifies the ECVRF proof `pi` of `alpha` under public key `y`. `Err`
+    /// carries the host's error code, or [`ECVRF_UNSUPPORTED`].
+    #[cfg(not(any(feature = "owasm-kit-v3", feature = "mock")))]
+    pub fn ecvrf_verify(_y: &[u8], _pi: &[u8], _alpha: &[u8]) -> Result<bool, u32> {
+        Err(ECVRF_UNSUPPORTED)
+    }
+}
+
+pub mod ext {
+    use super::{kit, oei};
+    use crate::error::ScriptError;
+
+    pub use kit::ext::{cmp, stats};
+
+    /// Returns the reports of external ID `eid` that have status 0.
+    pub fn load_input_raw(eid: i64) -> impl Iterator<Item = String> {
+        (0..oei::get_ask_count()).filter_map(move |vid| oei::get_external_data(eid, vid).ok())
+    }
+
+    /// Returns the successful reports of external ID `eid` that parse as `T`.
+    pub fn load_input<T>(eid: i64) -> impl Iterator<Item = T>
+    where
+        T: std::str::FromStr,
+    {
+        load_input_raw(eid).filter_map(|report| report.trim_end().parse::<T>().ok())
+    }
+
+    /// Returns the value reported by a majority of validators for external
+    /// ID `eid`, ignoring reports that fail or do not parse.
+    pub fn load_majority<T>(eid: i64) -> Option<T>
+    where
+        T: std::str::FromStr + std::cmp::PartialEq,
+    {
+        stats::majority(load_input(eid).collect())
+    }
+
+    /// Like [`load_majority`], but fails with [`ScriptError::NoReports`]
+    /// when no report is usable and with [`ScriptError::NoMajority`] when
+    /// the usable reports disagree, so a relayer can tell the two apart.
+    pub fn require_majority<T>(eid: i64) -> Result<T, ScriptError>
+    where
+        T: std::str::FromStr + std::cmp::PartialEq,
+    {
+        let reports: Vec<T> = load_input(eid).collect();
+        if reports.is_empty() {
+            return Err(ScriptError::NoReports(eid));
+        }
+        stats::majority(reports).ok_or(ScriptError::NoMajority(eid))
+    }
+}
@@ -0,0 +1,331 @@
+//! Native stand-in for the owasm host, so `cargo test` can run a script's
+//! `prepare` and `execute` entry points end to end.
+//!
+//! A [`Host`] describes one oracle request: calldata, ask and min counts, and
+//! what each validator reported for each external ID. With the `mock`
+//! feature, usually enabled from a script's dev-dependencies, [`Host::run`]
+//! installs it for the current thread while a phase runs, and hands it back
+//! with the raw requests asked for and the return data saved. Without the
+//! feature the types still describe requests, e.g. for a wasm runtime.
+//!
+//! ```ignore
+//! let (host, result) = Host::new(calldata).run(prepare);
+//! assert_eq!(host.asked[0].calldata, b"BTC ETH");
+//!
+//! let (host, result) = Host::new(calldata)
+//!     .ask_count(4)
+//!     .reports(0, ["100,10", "101,11", "garbage"])
+//!     .report(0, 3, Report::Failed(126))
+//!     .run(execute);
+//! ```
+
+#[cfg(feature = "mock")]
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+#[cfg(feature = "mock")]
+use std::panic::{self, UnwindSafe};
+
+use crate::error::ScriptError;
+
+/// Status returned for a validator that sent no report for an external ID.
+pub const MISSING_STATUS: i64 = -1;
+
+/// What one validator reported for one external ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Report {
+    /// The data source exited with status 0 and printed this.
+    Data(String),
+    /// The data source exited with this nonzero status.
+    Failed(i64),
+    /// The validator did not report.
+    Missing,
+}
+
+/// A raw request issued through `oei::ask_external_data`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AskedRequest {
+    pub eid: i64,
+    pub did: i64,
+    pub calldata: Vec<u8>,
+}
+
+/// Why a phase run under [`Host::run`] failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Abort {
+    pub message: String,
+}
+
+impl Abort {
+    /// The [`ScriptError`] code of the failure, if the script failed through
+    /// [`ScriptError::abort`].
+    pub fn code(&self) -> Option<u16> {
+        ScriptError::code_from_report(&self.message)
+    }
+}
+
+/// The host side of one oracle request.
+#[derive(Debug, Clone)]
+pub struct Host {
+    pub calldata: Vec<u8>,
+    pub ask_count: i64,
+    pub min_count: i64,
+    pub prepare_time: i64,
+    pub execute_time: i64,
+    /// What `oei::ecvrf_verify` answers, as the host cannot verify proofs.
+    pub ecvrf_result: Result<bool, u32>,
+    /// Reports per external ID, indexed by validator.
+    pub reports: BTreeMap<i64, Vec<Report>>,
+    /// Raw requests asked for so far, in call order.
+    pub asked: Vec<AskedRequest>,
+    pub return_data: Option<Vec<u8>>,
+}
+
+impl Host {
+    /// A request with the given calldata, asked of one validator.
+    pub fn new(calldata: impl Into<Vec<u8>>) -> Host {
+        Host {
+            calldata: calldata.into(),
+            ask_count: 1,
+            min_count: 1,
+            prepare_time: 0,
+            execute_time: 0,
+            ecvrf_result: Err(super::oei::ECVRF_UNSUPPORTED),
+            reports: BTreeMap::new(),
+            asked: Vec::new(),
+            return_data: None,
+        }
+    }
+
+    pub fn ask_count(mut self, ask_count: i64) -> Host {
+        self.ask_count = ask_count;
+        self
+    }
+
+    pub fn min_count(mut self, min_count: i64) -> Host {
+        self.min_count = min_count;
+        self
+    }
+
+    pub fn prepare_time(mut self, prepare_time: i64) -> Host {
+        self.prepare_time = prepare_time;
+        self
+    }
+
+    pub fn execute_time(mut self, execute_time: i64) -> Host {
+        self.execute_time = execute_time;
+        self
+    }
+
+    pub fn ecvrf_result(mut self, ecvrf_result: Result<bool, u32>) -> Host {
+        self.ecvrf_result = ecvrf_result;
+        self
+    }
+
+    /// Sets what validators 0, 1, ... reported for `eid`, all with status 0.
+    pub fn reports<I>(mut self, eid: i64, data: I) -> Host
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let reports = data.into_iter().map(|d| Report::Data(d.into())).collect();
+        self.reports.insert(eid, reports);
+        self
+    }
+
+    /// Sets what validator `vid` reported for `eid`.
+    pub fn report(mut self, eid: i64, vid: usize, report: Report) -> Host {
+        let reports = self.reports.entry(eid).or_default();
+        if reports.len() <= vid {
+            reports.resize(vid + 1, Report::Missing);
+        }
+        reports[vid] = report;
+        self
+    }
+
+    /// Validators that sent a report for at least one external ID.
+    pub fn ans_count(&self) -> i64 {
+        (0..self.ask_count)
+            .filter(|&vid| {
+                self.reports.values().any(|reports| {
+                    !matches!(reports.get(vid as usize), None | Some(Report::Missing))
+                })
+            })
+            .count() as i64
+    }
+
+    /// What validator `vid` reported for `eid`: the data, or the nonzero
+    /// status.
+    pub fn external_data(&self, eid: i64, vid: i64) -> Result<String, i64> {
+        if vid < 0 || vid >= self.ask_count {
+            return Err(MISSING_STATUS);
+        }
+        let report = self
+            .reports
+            .get(&eid)
+            .and_then(|reports| reports.get(vid as usize));
+        match report {
+            Some(Report::Data(data)) => Ok(data.clone()),
+            Some(Report::Failed(status)) => Err(*status),
+            Some(Report::Missing) | None => Err(MISSING_STATUS),
+        }
+    }
+
+    /// Runs `phase`, e.g. a script's `prepare` or `execute` entry point, with
+    /// this host installed for the current thread. Returns the host with
+    /// what the phase recorded, and why the phase failed if it did.
+    #[cfg(feature = "mock")]
+    pub fn run(self, phase: impl FnOnce() + UnwindSafe) -> (Host, Result<(), Abort>) {
+        HOST.with(|host| *host.borrow_mut() = Some(self));
+        let result = panic::catch_unwind(phase).map_err(|payload| {
+            let message = match payload.downcast::<String>() {
+                Ok(message) => *message,
+                Err(payload) => payload
+                    .downcast::<&str>()
+                    .map(|message| message.to_string())
+                    .unwrap_or_default(),
+            };
+            Abort { message }
+        });
+        let host = HOST
+            .with(|host| host.borrow_mut().take())
+            .expect("mock host was uninstalled while a phase ran");
+        (host, result)
+    }
+}
+
+#[cfg(feature = "mock")]
+thread_local! {
+    static HOST: RefCell<Option<Host>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "mock")]
+fn with_host<T>(f: impl FnOnce(&mut Host) -> T) -> T {
+    HOST.with(|host| {
+        let mut host = host.borrow_mut();
+        f(host
+            .as_mut()
+            .expect("host function called outside Host::run"))
+    })
+}
+
+/// Host functions of the mock, with the signatures of owasm-kit's `oei`.
+#[cfg(feature = "mock")]
+pub mod oei {
+    use super::{with_host, AskedRequest};
+
+    pub fn get_ask_count() -> i64 {
+        with_host(|host| host.ask_count)
+    }
+
+    pub fn get_min_count() -> i64 {
+        with_host(|host| host.min_count)
+    }
+
+    pub fn get_prepare_time() -> i64 {
+        with_host(|host| host.prepare_time)
+    }
+
+    pub fn get_execute_time() -> i64 {
+        with_host(|host| host.execute_time)
+    }
+
+    pub fn get_ans_count() -> i64 {
+        with_host(|host| host.ans_count())
+    }
+
+    pub fn get_calldata() -> Vec<u8> {
+        with_host(|host| host.calldata.clone())
+    }
+
+    pub fn save_return_data(data: &[u8]) {
+        with_host(|host| host.return_data = Some(data.to_vec()))
+    }
+
+    pub fn ask_external_data(eid: i64, did: i64, calldata: &[u8]) {
+        with_host(|host| {
+            host.asked.push(AskedRequest {
+                eid,
+                did,
+                calldata: calldata.to_vec(),
+            })
+        })
+    }
+
+    pub fn get_external_data(eid: i64, vid: i64) -> Result<String, i64> {
+        with_host(|host| host.external_data(eid, vid))
+    }
+
+    pub fn ecvrf_verify(_y: &[u8], _pi: &[u8], _alpha: &[u8]) -> Result<bool, u32> {
+        with_host(|host| host.ecvrf_result)
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::host::{ext, oei as host_oei};
+
+    #[test]
+    fn test_records_asked_requests() {
+        let (host, result) = Host::new(b"in".to_vec()).run(|| {
+            assert_eq!(host_oei::get_calldata(), b"in");
+            host_oei::ask_external_data(1, 11, b"BTC");
+            host_oei::ask_external_data(2, 12, b"ETH");
+            host_oei::save_return_data(b"out");
+        });
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            host.asked,
+            vec![
+                AskedRequest {
+                    eid: 1,
+                    did: 11,
+                    calldata: b"BTC".to_vec()
+                },
+                AskedRequest {
+                    eid: 2,
+                    did: 12,
+                    calldata: b"ETH".to_vec()
+                },
+            ]
+        );
+        assert_eq!(host.return_data, Some(b"out".to_vec()));
+    }
+
+    #[test]
+    fn test_replays_reports() {
+        let host = Host::new(vec![])
+            .ask_count(5)
+            .min_count(3)
+            .reports(1, ["7", "7\n", "garbage", "8"])
+            .report(1, 1, Report::Failed(126))
+            .reports(2, ["x"]);
+        let (_, result) = host.run(|| {
+            assert_eq!(host_oei::get_min_count(), 3);
+            assert_eq!(host_oei::get_ans_count(), 4);
+            assert_eq!(host_oei::get_external_data(1, 1), Err(126));
+            assert_eq!(host_oei::get_external_data(1, 4), Err(MISSING_STATUS));
+            let raw: Vec<String> = ext::load_input_raw(1).collect();
+            assert_eq!(raw, vec!["7", "garbage", "8"]);
+            assert_eq!(ext::load_input::<u64>(1).collect::<Vec<_>>(), vec![7, 8]);
+            assert_eq!(ext::load_majority::<u64>(1), None);
+            assert_eq!(ext::load_majority::<String>(2), Some("x".into()));
+            assert_eq!(
+                ext::require_majority::<u64>(1),
+                Err(ScriptError::NoMajority(1))
+            );
+            assert_eq!(ext::require_majority::<String>(2), Ok("x".into()));
+            assert_eq!(
+                ext::require_majority::<u64>(2),
+                Err(ScriptError::NoReports(2))
+            );
+        });
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_reports_script_errors() {
+        let (_, result) = Host::new(vec![]).run(|| ScriptError::NoReports(3).abort());
+        assert_eq!(result.unwrap_err().code(), Some(300));
+    }
+}
@@ -0,0 +1,248 @@
+//! Golden replay fixtures, run against scripts through the [`mock`] host.
+//!
+//! A fixture is a TOML file naming the scripts it applies to, the
+//! OBI-encoded input of a request, what validators reported for each
+//! external ID, and the expected output or [`ScriptError`] code:
+//!
+//! ```toml
+//! # {symbols: ["BTC"], multiplier: 100}
+//! scripts = ["standard_dataset_crypto_mainnet"]
+//! input = "00000001000000034254430000000000000064"
+//! ask_count = 3  # defaults to the longest report list
+//! min_count = 2  # defaults to ask_count
+//! ecvrf = true    # what oei::ecvrf_verify answers, unsupported if unset
+//!
+//! [reports]
+//! 1 = ["100", "101", "garbage"]
+//!
+//! [expect]
+//! output = "000000010000000000002774"  # or: error = 302
+//! ```
+//!
+//! Report lists are indexed by validator; validators past the end of a list
+//! did not report. Every script replays the checked-in fixtures that name it
+//! with [`replay_fixtures`] from one of its tests, and os-harness runs those
+//! tests again under each network profile of the script. A build variant is
+//! named `<script>+<feature>`, e.g. `linear_os+partial`.
+//!
+//! [`mock`]: super::mock
+//! [`ScriptError`]: crate::ScriptError
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::mock::Host;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FixtureFile {
+    scripts: Vec<String>,
+    input: String,
+    ask_count: Option<i64>,
+    min_count: Option<i64>,
+    ecvrf: Option<bool>,
+    #[serde(default)]
+    reports: BTreeMap<String, Vec<String>>,
+    expect: ExpectFile,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase", deny_unknown_fields)]
+enum ExpectFile {
+    Output(String),
+    Error(u16),
+}
+
+/// How a replayed request ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// Execute saved this return data.
+    Output(Vec<u8>),
+    /// A phase failed with this [`ScriptError`](crate::ScriptError) code.
+    Error(u16),
+    /// A phase failed without a script error code, with this message.
+    Panic(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Output(data) => write!(f, "output {}", hex::encode(data)),
+            Outcome::Error(code) => write!(f, "error {}", code),
+            Outcome::Panic(message) => write!(f, "panic {:?}", message),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    /// File stem of the fixture.
+    pub name: String,
+    pub scripts: Vec<String>,
+    /// The request before prepare runs, reports included.
+    pub host: Host,
+    pub expect: Outcome,
+}
+
+impl Fixture {
+    pub fn load(path: &Path) -> Result<Fixture, String> {
+        let fail = |err: &dyn fmt::Display| format!("{}: {}", path.display(), err);
+        let text = fs::read_to_string(path).map_err(|err| fail(&err))?;
+        let file: FixtureFile = toml::from_str(&text).map_err(|err| fail(&err))?;
+        let input = hex::decode(&file.input).map_err(|err| fail(&err))?;
+        let expect = match file.expect {
+            ExpectFile::Output(data) => {
+                Outcome::Output(hex::decode(data).map_err(|err| fail(&err))?)
+            }
+            ExpectFile::Error(code) => Outcome::Error(code),
+        };
+
+        let longest = file.reports.values().map(Vec::len).max().unwrap_or(0);
+        let ask_count = file.ask_count.unwrap_or(longest.max(1) as i64);
+        let mut host = Host::new(input)
+            .ask_count(ask_count)
+            .min_count(file.min_count.unwrap_or(ask_count));
+        if let Some(valid) = file.ecvrf {
+            host = host.ecvrf_result(Ok(valid));
+        }
+        for (eid, reports) in file.reports {
+            let eid = eid
+                .parse()
+                .map_err(|_| fail(&format!("bad external ID {:?}", eid)))?;
+            host = host.reports(eid, reports);
+        }
+        Ok(Fixture {
+            name: path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            scripts: file.scripts,
+            host,
+            expect,
+        })
+    }
+
+    /// Runs the request through a script's `prepare` and `execute` entry
+    /// points and returns how it ends.
+    pub fn replay(&self, prepare: fn(), execute: fn()) -> Outcome {
+        let failed = |abort: super::mock::Abort| match abort.code() {
+            Some(code) => Outcome::Error(code),
+            None => Outcome::Panic(abort.message),
+        };
+        let (host, result) = self.host.clone().run(prepare);
+        if let Err(abort) = result {
+            return failed(abort);
+        }
+        let (host, result) = host.run(execute);
+        match result {
+            Ok(()) => Outcome::Output(host.return_data.unwrap_or_default()),
+            Err(abort) => failed(abort),
+        }
+    }
+}
+
+/// Loads every `*.toml` fixture of `dir`, sorted by name.
+pub fn load_fixtures(dir: &Path) -> Result<Vec<Fixture>, String> {
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .map_err(|err| format!("{}: {}", dir.display(), err))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+    paths.iter().map(|path| Fixture::load(path)).collect()
+}
+
+/// Replays every fixture of `dir` that names `script`, and panics listing
+/// each one whose outcome differs from the expected one.
+pub fn replay_fixtures(dir: &str, script: &str, prepare: fn(), execute: fn()) {
+    let fixtures = load_fixtures(Path::new(dir)).unwrap_or_else(|err| panic!("{}", err));
+    let mismatches: Vec<String> = fixtures
+        .iter()
+        .filter(|fixture| fixture.scripts.iter().any(|name| name == script))
+        .filter_map(|fixture| {
+            let outcome = fixture.replay(prepare, execute);
+            (outcome != fixture.expect).then(|| {
+                format!(
+                    "{}: expected {}, got {}",
+                    fixture.name, fixture.expect, outcome
+                )
+            })
+        })
+        .collect();
+    assert!(
+        mismatches.is_empty(),
+        "{} fixtures failed:\n{}",
+        script,
+        mismatches.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../fixtures");
+
+    #[test]
+    fn test_checked_in_fixtures_name_scripts() {
+        let scripts_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../scripts");
+        for fixture in load_fixtures(Path::new(FIXTURE_DIR)).unwrap() {
+            assert!(
+                !fixture.scripts.is_empty(),
+                "{} names no script",
+                fixture.name
+            );
+            for script in fixture.scripts.iter() {
+                let crate_name = script.split('+').next().unwrap();
+                assert!(
+                    scripts_dir.join(crate_name).join("Cargo.toml").is_file(),
+                    "{} names unknown script {}",
+                    fixture.name,
+                    script
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_script_has_fixtures() {
+        let named: Vec<String> = load_fixtures(Path::new(FIXTURE_DIR))
+            .unwrap()
+            .into_iter()
+            .flat_map(|fixture| fixture.scripts)
+            .collect();
+        let scripts_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../scripts");
+        for entry in fs::read_dir(scripts_dir).unwrap() {
+            let script = entry.unwrap().file_name().to_string_lossy().into_owned();
+            assert!(
+                named.contains(&script),
+                "no fixture names {}, add one to fixtures/",
+                script
+            );
+        }
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("os-common-replay-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("sample.toml");
+        fs::write(
+            &path,
+            "scripts = [\"s\"]\ninput = \"01ff\"\n\n[reports]\n1 = [\"a\", \"b\"]\n\n[expect]\nerror = 302\n",
+        )
+        .unwrap();
+        let fixture = Fixture::load(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(fixture.name, "sample");
+        assert_eq!(fixture.host.calldata, vec![0x01, 0xff]);
+        assert_eq!((fixture.host.ask_count, fixture.host.min_count), (2, 2));
+        assert_eq!(fixture.host.external_data(1, 1), Ok("b".into()));
+        assert_eq!(fixture.expect, Outcome::Error(302));
+    }
+}
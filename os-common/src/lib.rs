@@ -0,0 +1,52 @@
+//! Helpers shared by the oracle scripts in this repository.
+//!
+//! Every price script used to carry its own copy of the exchange-map and
+//! median helpers. They live here now, written once against the
+//! [`PriceScript`] trait that describes a script's symbol registry and
+//! exchange table. A script's raw requests come from its [`PreparePlan`],
+//! whose order depends on the input alone, and [`aggregate_rates`] turns
+//! their reports into rates with the [`Strategy`] of each [`Aggregation`]
+//! stage. [`aggregate_partial_rates`] gives every symbol a [`RateStatus`]
+//! instead, so one symbol without a rate does not fail the others; the price
+//! scripts use it in their `partial` build. A price script only declares its
+//! [`PriceScript`] and leaves its inputs, outputs and entry points to
+//! [`price_script!`].
+//!
+//! Reports are read through the [`report`] parsers, which reject a report
+//! rather than guess at it. Prices are parsed into [`Decimal`]s, so rates
+//! are computed without floating point.
+//!
+//! The [`host`] module is the one place scripts reach the owasm host from, so
+//! they build against either owasm-kit line. Scripts fail through
+//! [`ScriptError`], whose stable codes tell a relayer why a request failed.
+
+pub mod coverage;
+pub mod decimal;
+pub mod error;
+#[cfg(any(feature = "owasm-kit-v1", feature = "owasm-kit-v3"))]
+pub mod host;
+pub mod pipeline;
+pub mod plan;
+pub mod price;
+pub mod report;
+pub mod schema;
+pub mod stats;
+pub mod symbol;
+pub mod vrf;
+
+pub use coverage::ExchangeSet;
+pub use decimal::Decimal;
+pub use error::ScriptError;
+pub use pipeline::{
+    aggregate_partial_rates, aggregate_rates, output_rates, partial_output_rates,
+    rejected_exchanges, Aggregation, RateStatus, RejectedExchanges, SymbolRate,
+    DEFAULT_OUTLIER_CUTOFF, DEFAULT_OUTLIER_FLOOR,
+};
+pub use plan::{PreparePlan, RawRequest};
+pub use price::{
+    canonical_symbol, exchange_from_u64, get_ds_from_exchange, get_ds_input,
+    get_symbols_from_input, PriceScript,
+};
+pub use schema::obi_schema;
+pub use stats::Strategy;
+pub use symbol::{AliasMap, SymbolInfo, SymbolMap};
@@ -0,0 +1,696 @@
+use std::collections::BTreeMap;
+
+use obi::{OBIDecode, OBIEncode, OBISchema};
+
+use crate::coverage::ExchangeSet;
+use crate::decimal::Decimal;
+use crate::error::ScriptError;
+use crate::plan::PreparePlan;
+use crate::price::PriceScript;
+use crate::report::parse_prices;
+use crate::stats::{mad_outliers, Strategy};
+
+/// Cut-off of the outlier stage of the price scripts, in median absolute
+/// deviations.
+pub const DEFAULT_OUTLIER_CUTOFF: Decimal = Decimal::from_integer(3);
+
+/// Least median absolute deviation of the outlier stage, as a fraction of
+/// the median price: 0.5%.
+pub const DEFAULT_OUTLIER_FLOOR: Decimal = Decimal::from_units(5_000_000_000_000_000);
+
+/// The strategy of each stage of [`aggregate_rates`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Aggregation {
+    /// Combines the prices validators got from one exchange.
+    pub validators: Strategy,
+    /// Rejects the exchanges whose price is farther than this many median
+    /// absolute deviations from the others', see [`mad_outliers`]. `None`
+    /// keeps every exchange.
+    pub outlier_cutoff: Option<Decimal>,
+    /// Least median absolute deviation of the outlier stage, as a fraction
+    /// of the median price, so that exchanges agreeing to the cent do not
+    /// make every other one an outlier.
+    pub outlier_floor: Decimal,
+    /// Combines the prices of the exchanges, each weighted by the number of
+    /// validators it got a price through.
+    pub exchanges: Strategy,
+    /// Exchanges every rate needs prices from, on top of the `min_sources`
+    /// of each symbol, e.g. as requested in the input.
+    pub min_sources: u8,
+}
+
+impl Aggregation {
+    /// The median at both stages.
+    pub const MEDIAN: Aggregation = Aggregation {
+        validators: Strategy::Median,
+        outlier_cutoff: None,
+        outlier_floor: DEFAULT_OUTLIER_FLOOR,
+        exchanges: Strategy::Median,
+        min_sources: 1,
+    };
+
+    /// Rejects outlying exchanges before the exchange stage.
+    pub const fn reject_outliers(self, cutoff: Decimal) -> Aggregation {
+        Aggregation {
+            outlier_cutoff: Some(cutoff),
+            ..self
+        }
+    }
+}
+
+impl Default for Aggregation {
+    fn default() -> Aggregation {
+        Aggregation::MEDIAN
+    }
+}
+
+/// Whether a requested symbol got a rate, and why not.
+///
+/// Partial outputs carry [`RateStatus::code`] next to each rate. Like
+/// [`ScriptError::code`], a code never changes meaning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RateStatus {
+    Ok,
+    /// The script does not serve the symbol.
+    Unsupported,
+    /// No exchange returned a price for the symbol, or its rate is 0.
+    NoData,
+    /// Fewer exchanges than the symbol needs returned a price for it.
+    BelowQuorum {
+        sources: usize,
+        min_sources: usize,
+    },
+    /// Enough exchanges returned a price for the symbol, but too few are
+    /// left once outliers are rejected.
+    OutlierRejected {
+        sources: usize,
+        min_sources: usize,
+    },
+    /// The rate at the requested multiplier does not fit in the integer the
+    /// output encodes rates as, see [`partial_output_rates`].
+    Overflow,
+}
+
+impl RateStatus {
+    pub fn code(&self) -> u8 {
+        match self {
+            RateStatus::Ok => 0,
+            RateStatus::Unsupported => 1,
+            RateStatus::NoData => 2,
+            RateStatus::BelowQuorum { .. } => 3,
+            RateStatus::OutlierRejected { .. } => 4,
+            RateStatus::Overflow => 5,
+        }
+    }
+
+    /// The error a symbol without a rate fails an all-or-nothing request
+    /// with.
+    pub fn error(&self, symbol: &str) -> Option<ScriptError> {
+        match *self {
+            RateStatus::Ok => None,
+            RateStatus::Unsupported => Some(ScriptError::UnknownSymbol(symbol.to_string())),
+            RateStatus::NoData => Some(ScriptError::NoPrice(symbol.to_string())),
+            RateStatus::Overflow => Some(ScriptError::RateOverflow(symbol.to_string())),
+            RateStatus::BelowQuorum {
+                sources,
+                min_sources,
+            }
+            | RateStatus::OutlierRejected {
+                sources,
+                min_sources,
+            } => Some(ScriptError::TooFewSources {
+                symbol: symbol.to_string(),
+                sources,
+                min_sources,
+            }),
+        }
+    }
+}
+
+/// The rate of one requested symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolRate {
+    /// 0 unless `status` is [`RateStatus::Ok`]. Exact up to `u128::MAX`;
+    /// outputs narrow it with [`output_rates`] or [`partial_output_rates`].
+    pub rate: u128,
+    pub status: RateStatus,
+    /// Exchanges left out as outliers.
+    pub rejected: ExchangeSet,
+}
+
+impl SymbolRate {
+    fn unrated(status: RateStatus, rejected: ExchangeSet) -> SymbolRate {
+        SymbolRate {
+            rate: 0,
+            status,
+            rejected,
+        }
+    }
+}
+
+/// The exchanges rejected as outliers for one requested symbol, as the
+/// outputs of scripts whose aggregation rejects outliers carry them.
+#[derive(OBIEncode, OBIDecode, OBISchema, Debug, Clone, PartialEq, Eq)]
+pub struct RejectedExchanges {
+    /// Index of the symbol in the request.
+    pub symbol: u32,
+    /// One bit per exchange ID, see [`ExchangeSet`].
+    pub exchanges: u64,
+}
+
+/// The rates of an all-or-nothing output, as the integers `R` it encodes
+/// them as, e.g. `u64`. A rate that does not fit fails the request with
+/// [`ScriptError::RateOverflow`].
+pub fn output_rates<R: TryFrom<u128>>(
+    rates: &[SymbolRate],
+    symbols: &[String],
+) -> Result<Vec<R>, ScriptError> {
+    rates
+        .iter()
+        .zip(symbols)
+        .map(|(rate, symbol)| {
+            R::try_from(rate.rate).map_err(|_| ScriptError::RateOverflow(symbol.to_string()))
+        })
+        .collect()
+}
+
+/// The rates and [`RateStatus::code`]s of a partial output, the rates as the
+/// integers `R` it encodes them as. A rate that does not fit is 0, with
+/// [`RateStatus::Overflow`].
+pub fn partial_output_rates<R: TryFrom<u128> + Default>(rates: &[SymbolRate]) -> (Vec<R>, Vec<u8>) {
+    rates
+        .iter()
+        .map(|rate| match R::try_from(rate.rate) {
+            Ok(encoded) => (encoded, rate.status.code()),
+            Err(_) => (R::default(), RateStatus::Overflow.code()),
+        })
+        .unzip()
+}
+
+/// Lists the symbols of `rates` that had exchanges rejected, leaving out the
+/// others so the output stays small.
+pub fn rejected_exchanges(rates: &[SymbolRate]) -> Vec<RejectedExchanges> {
+    rates
+        .iter()
+        .enumerate()
+        .filter(|(_, rate)| !rate.rejected.is_empty())
+        .map(|(symbol, rate)| RejectedExchanges {
+            symbol: symbol as u32,
+            exchanges: rate.rejected.bits(),
+        })
+        .collect()
+}
+
+/// Computes the rate of every requested symbol from the reports of a plan's
+/// raw requests: the prices validators got from each exchange are combined
+/// with `aggregation.validators`, outlying exchanges are rejected, then the
+/// prices of the others are combined with `aggregation.exchanges` and scaled
+/// by `multiplier`. Prices are [`Decimal`]s throughout, and the scaled rate
+/// is rounded down.
+///
+/// All or nothing: a symbol without a rate fails the request with the error
+/// of its [`RateStatus`], e.g. [`ScriptError::TooFewSources`] when fewer
+/// exchanges are left than it or `aggregation` needs, and
+/// [`ScriptError::NoPrice`] rather than have a rate of 0.
+///
+/// `load_reports` returns the successful reports of an external ID, e.g.
+/// `ext::load_input::<String>(eid).collect()`. It is called once per raw
+/// request, in plan order.
+pub fn aggregate_rates<S: PriceScript>(
+    plan: &PreparePlan,
+    symbols: &[String],
+    multiplier: u64,
+    aggregation: &Aggregation,
+    load_reports: impl FnMut(i64) -> Vec<String>,
+) -> Result<Vec<SymbolRate>, ScriptError> {
+    let rates = aggregate_partial_rates::<S>(plan, symbols, multiplier, aggregation, load_reports)?;
+    for (symbol, rate) in symbols.iter().zip(rates.iter()) {
+        if let Some(err) = rate.status.error(symbol) {
+            return Err(err);
+        }
+    }
+    Ok(rates)
+}
+
+/// Like [`aggregate_rates`], but a symbol without a rate only gets a rate of
+/// 0 and the [`RateStatus`] saying why, so the other symbols still resolve.
+/// Takes the plan of [`PreparePlan::build_partial`].
+pub fn aggregate_partial_rates<S: PriceScript>(
+    plan: &PreparePlan,
+    symbols: &[String],
+    multiplier: u64,
+    aggregation: &Aggregation,
+    mut load_reports: impl FnMut(i64) -> Vec<String>,
+) -> Result<Vec<SymbolRate>, ScriptError> {
+    // the external ID and price of each exchange that reported, per symbol,
+    // with the number of validators the price came through
+    let mut symbol_pxs: BTreeMap<&'static str, Vec<(u64, Decimal, u64)>> = BTreeMap::new();
+    for request in plan.requests.iter() {
+        let eid = request.external_id as i64;
+        let reports = load_reports(eid);
+        if reports.is_empty() {
+            continue;
+        }
+        let mut prices = vec![vec![]; request.symbols.len()];
+        // for each validator response for the exchange,
+        // split the response into one slot per requested symbol, leaving out
+        // responses with a field that is not a price or with a field too
+        // many or too few, which would put prices on the wrong symbols
+        for raw in reports {
+            let px_list = match parse_prices(&raw, request.symbols.len()) {
+                Some(px_list) => px_list,
+                None => continue,
+            };
+            // for each token price, add it to the list of validator responses
+            // for that token and exchange, unless it is marked missing
+            for (pxs, px) in prices.iter_mut().zip(px_list) {
+                pxs.extend(px.map(|px| (px, 1)));
+            }
+        }
+        for (&symbol, mut pxs) in request.symbols.iter().zip(prices) {
+            // an exchange no validator got a price from has no say
+            if let Some(px) = aggregation.validators.aggregate(&mut pxs) {
+                let weight = pxs.len() as u64;
+                symbol_pxs
+                    .entry(symbol)
+                    .or_default()
+                    .push((request.external_id, px, weight));
+            }
+        }
+    }
+
+    symbols
+        .iter()
+        .map(|symbol| {
+            // Aliases of one symbol share its prices, in the requested order
+            let (name, info) = match S::lookup(symbol) {
+                Some(found) => found,
+                None => {
+                    return Ok(SymbolRate::unrated(
+                        RateStatus::Unsupported,
+                        ExchangeSet::default(),
+                    ))
+                }
+            };
+            let exchange_pxs = match symbol_pxs.get(name) {
+                Some(exchange_pxs) => exchange_pxs,
+                None => {
+                    return Ok(SymbolRate::unrated(
+                        RateStatus::NoData,
+                        ExchangeSet::default(),
+                    ))
+                }
+            };
+            let pxs: Vec<Decimal> = exchange_pxs.iter().map(|&(_, px, _)| px).collect();
+            let outliers = match aggregation.outlier_cutoff {
+                Some(cutoff) => mad_outliers(&pxs, cutoff, aggregation.outlier_floor),
+                None => vec![false; pxs.len()],
+            };
+
+            let mut kept = Vec::new();
+            let mut rejected = ExchangeSet::default();
+            for (&(eid, px, weight), outlier) in exchange_pxs.iter().zip(outliers) {
+                match outlier {
+                    true => match eid < ExchangeSet::CAPACITY {
+                        true => rejected.insert(eid),
+                        false => return Err(ScriptError::UnknownExchange(eid)),
+                    },
+                    false => kept.push((px, weight)),
+                }
+            }
+            let min_sources = info.min_sources.max(aggregation.min_sources) as usize;
+            if kept.len() < min_sources {
+                let sources = kept.len();
+                let status = match exchange_pxs.len() < min_sources {
+                    true => RateStatus::BelowQuorum {
+                        sources,
+                        min_sources,
+                    },
+                    false => RateStatus::OutlierRejected {
+                        sources,
+                        min_sources,
+                    },
+                };
+                return Ok(SymbolRate::unrated(status, rejected));
+            }
+            let rate = aggregation
+                .exchanges
+                .aggregate(&mut kept)
+                .map(|px| px.scale(multiplier));
+            match rate {
+                None | Some(Some(0)) => Ok(SymbolRate::unrated(RateStatus::NoData, rejected)),
+                Some(None) => Ok(SymbolRate::unrated(RateStatus::Overflow, rejected)),
+                Some(Some(rate)) => Ok(SymbolRate {
+                    rate,
+                    status: RateStatus::Ok,
+                    rejected,
+                }),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::price::tests::{symbols, Script};
+    use std::collections::HashMap;
+
+    fn run(requested: &[&str], reports: &[(i64, &[&str])]) -> Result<Vec<u128>, ScriptError> {
+        run_with(&Aggregation::MEDIAN, requested, reports)
+    }
+
+    fn run_with(
+        aggregation: &Aggregation,
+        requested: &[&str],
+        reports: &[(i64, &[&str])],
+    ) -> Result<Vec<u128>, ScriptError> {
+        let rates = symbol_rates(aggregation, requested, reports)?;
+        Ok(rates.into_iter().map(|rate| rate.rate).collect())
+    }
+
+    fn symbol_rates(
+        aggregation: &Aggregation,
+        requested: &[&str],
+        reports: &[(i64, &[&str])],
+    ) -> Result<Vec<SymbolRate>, ScriptError> {
+        let requested = symbols(requested);
+        let plan = PreparePlan::build::<Script>(&requested)?;
+        let reports: HashMap<i64, Vec<String>> = reports
+            .iter()
+            .map(|(eid, rs)| (*eid, rs.iter().map(|r| r.to_string()).collect()))
+            .collect();
+        aggregate_rates::<Script>(&plan, &requested, 100, aggregation, |eid| {
+            reports.get(&eid).cloned().unwrap_or_default()
+        })
+    }
+
+    #[test]
+    fn test_aggregate_rates() {
+        // Plan symbols: 0 => BAND BTC, 1 => BTC ETH, 2 => BTC ETH
+        let rates = run(
+            &["ETH", "BTC", "BAND"],
+            &[
+                (0, &["1.5,100", "1.7,102", "1.6,101"]),
+                (1, &["100,10", "104,12"]),
+                (2, &["99,11"]),
+            ],
+        );
+        assert_eq!(rates, Ok(vec![1100, 10100, 160]));
+    }
+
+    #[test]
+    fn test_aggregate_rates_skips_silent_exchanges() {
+        let rates = run(&["BTC", "xbt"], &[(1, &["100"]), (2, &["102"])]);
+        assert_eq!(rates, Ok(vec![10100, 10100]));
+    }
+
+    #[test]
+    fn test_aggregate_rates_rejects_unreadable_reports() {
+        // "abc" would otherwise shift ETH's 12 onto BTC
+        let rates = run(&["BTC", "ETH"], &[(1, &["100,10", "abc,12", "104,11"])]);
+        assert_eq!(rates, Ok(vec![10200, 1050]));
+        // A validator sending a field too many loses its own report only
+        let rates = run(&["BTC", "ETH"], &[(1, &["100,10", "1,2,3", "104,11"])]);
+        assert_eq!(rates, Ok(vec![10200, 1050]));
+    }
+
+    #[test]
+    fn test_aggregate_rates_keeps_positions() {
+        // Fields too many or too few are not matched to symbols by position
+        let rates = run(
+            &["BTC", "ETH"],
+            &[(1, &["100,10", "12", "100,11,7", "104,-"]), (2, &["-,13"])],
+        );
+        assert_eq!(rates, Ok(vec![10200, 1150]));
+        // An exchange whose validators all miss a symbol does not count as 0
+        let rates = run(&["BTC", "ETH"], &[(1, &["-,10"]), (2, &["102,11"])]);
+        assert_eq!(rates, Ok(vec![10200, 1050]));
+    }
+
+    #[test]
+    fn test_aggregate_rates_strategies() {
+        // Exchange 1 has three validators, exchange 2 one
+        let reports: &[(i64, &[&str])] = &[(1, &["100", "104", "110"]), (2, &["90"])];
+        let rates = |validators, exchanges| {
+            let aggregation = Aggregation {
+                validators,
+                exchanges,
+                ..Aggregation::MEDIAN
+            };
+            run_with(&aggregation, &["BTC"], reports)
+        };
+        assert_eq!(rates(Strategy::Median, Strategy::Median), Ok(vec![9700]));
+        assert_eq!(
+            rates(Strategy::Median, Strategy::UpperMedian),
+            Ok(vec![10400])
+        );
+        assert_eq!(
+            rates(Strategy::Median, Strategy::WeightedMedian),
+            Ok(vec![10400])
+        );
+        assert_eq!(
+            rates(Strategy::Median, Strategy::LowerMedian),
+            Ok(vec![9000])
+        );
+        assert_eq!(
+            rates(Strategy::TrimmedMean { percent: 0 }, Strategy::LowerMedian),
+            Ok(vec![9000])
+        );
+        assert_eq!(
+            rates(Strategy::TrimmedMean { percent: 0 }, Strategy::UpperMedian),
+            Ok(vec![10466])
+        );
+    }
+
+    #[test]
+    fn test_aggregate_rates_rejects_outliers() {
+        // CRYPTOCOMPARE sends a stale BTC price; ETH is only on two exchanges
+        let reports: &[(i64, &[&str])] =
+            &[(0, &["100"]), (1, &["101,10", "101,10"]), (2, &["150,30"])];
+        let aggregation = Aggregation::MEDIAN.reject_outliers(DEFAULT_OUTLIER_CUTOFF);
+        assert_eq!(
+            symbol_rates(&aggregation, &["BTC", "ETH"], reports),
+            Ok(vec![
+                SymbolRate {
+                    rate: 10050,
+                    status: RateStatus::Ok,
+                    rejected: ExchangeSet::from_ids(&[2]),
+                },
+                SymbolRate {
+                    rate: 2000,
+                    status: RateStatus::Ok,
+                    rejected: ExchangeSet::default(),
+                },
+            ])
+        );
+        assert_eq!(
+            run_with(&Aggregation::MEDIAN, &["BTC", "ETH"], reports),
+            Ok(vec![10100, 2000])
+        );
+    }
+
+    #[test]
+    fn test_aggregate_rates_quorum() {
+        // DOT needs two exchanges, BTC one unless asked for more
+        let reports: &[(i64, &[&str])] = &[(0, &["100"]), (1, &["101"])];
+        assert_eq!(run(&["BTC"], reports), Ok(vec![10050]));
+        assert_eq!(run(&["DOT"], reports), Ok(vec![10050]));
+        assert_eq!(
+            run(&["DOT"], &[(0, &["100"]), (1, &["-"])]),
+            Err(ScriptError::TooFewSources {
+                symbol: "DOT".into(),
+                sources: 1,
+                min_sources: 2,
+            })
+        );
+        let quorum = Aggregation {
+            min_sources: 3,
+            ..Aggregation::MEDIAN
+        };
+        assert_eq!(
+            run_with(&quorum, &["BTC"], reports),
+            Err(ScriptError::TooFewSources {
+                symbol: "BTC".into(),
+                sources: 2,
+                min_sources: 3,
+            })
+        );
+
+        // A rejected outlier does not count towards the quorum
+        let reports: &[(i64, &[&str])] = &[(0, &["10"]), (1, &["10"]), (2, &["90"])];
+        let robust = Aggregation::MEDIAN.reject_outliers(DEFAULT_OUTLIER_CUTOFF);
+        assert_eq!(run_with(&robust, &["DOT"], reports), Ok(vec![1000]));
+        assert_eq!(
+            run_with(
+                &Aggregation {
+                    min_sources: 3,
+                    ..robust
+                },
+                &["DOT"],
+                reports
+            ),
+            Err(ScriptError::TooFewSources {
+                symbol: "DOT".into(),
+                sources: 2,
+                min_sources: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_aggregate_partial_rates() {
+        let requested = symbols(&["BTC", "DOGE", "BAND", "DOT", "ETH"]);
+        let plan = PreparePlan::build_partial::<Script>(&requested).unwrap();
+        // BTC and DOT have three exchanges, of which one is an outlier; no
+        // exchange prices BAND and ETH has one of the two it needs
+        let reports: HashMap<i64, Vec<String>> = [(0, "-,10,10"), (1, "10,10,10"), (2, "90,90,-")]
+            .into_iter()
+            .map(|(eid, report)| (eid, vec![report.to_string()]))
+            .collect();
+        let aggregation = Aggregation {
+            min_sources: 2,
+            ..Aggregation::MEDIAN.reject_outliers(DEFAULT_OUTLIER_CUTOFF)
+        };
+        let rates =
+            aggregate_partial_rates::<Script>(&plan, &requested, 100, &aggregation, |eid| {
+                reports.get(&eid).cloned().unwrap_or_default()
+            })
+            .unwrap();
+        let statuses: Vec<(u128, u8)> = rates
+            .iter()
+            .map(|rate| (rate.rate, rate.status.code()))
+            .collect();
+        assert_eq!(statuses, vec![(1000, 0), (0, 1), (0, 2), (1000, 0), (0, 3)]);
+        assert_eq!(
+            rejected_exchanges(&rates),
+            vec![
+                RejectedExchanges {
+                    symbol: 0,
+                    exchanges: 0b100,
+                },
+                RejectedExchanges {
+                    symbol: 3,
+                    exchanges: 0b100,
+                },
+            ]
+        );
+
+        let quorum = Aggregation {
+            min_sources: 3,
+            ..aggregation
+        };
+        let rates = aggregate_partial_rates::<Script>(&plan, &requested, 100, &quorum, |eid| {
+            reports.get(&eid).cloned().unwrap_or_default()
+        })
+        .unwrap();
+        assert_eq!(
+            rates[0],
+            SymbolRate {
+                rate: 0,
+                status: RateStatus::OutlierRejected {
+                    sources: 2,
+                    min_sources: 3,
+                },
+                rejected: ExchangeSet::from_ids(&[2]),
+            }
+        );
+        assert_eq!(
+            rates[0].status.error("BTC"),
+            Some(ScriptError::TooFewSources {
+                symbol: "BTC".into(),
+                sources: 2,
+                min_sources: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_aggregate_rates_never_emits_zero() {
+        assert_eq!(
+            run(&["BTC"], &[(1, &["0.001"])]),
+            Err(ScriptError::NoPrice("BTC".into()))
+        );
+        assert_eq!(
+            run(&["BTC"], &[(1, &["0"])]),
+            Err(ScriptError::NoPrice("BTC".into()))
+        );
+    }
+
+    #[test]
+    fn test_aggregate_rates_is_exact() {
+        let rates = |multiplier, reports: &[&str]| {
+            let requested = symbols(&["BTC"]);
+            let plan = PreparePlan::build::<Script>(&requested).unwrap();
+            let reports: Vec<String> = reports.iter().map(|r| r.to_string()).collect();
+            aggregate_rates::<Script>(&plan, &requested, multiplier, &Aggregation::MEDIAN, |eid| {
+                match eid {
+                    1 => reports.clone(),
+                    _ => vec![],
+                }
+            })
+            .map(|rates| rates[0].rate)
+        };
+        // Past 2^53, where an f64 median would round
+        assert_eq!(
+            rates(1, &["9007199254740993", "9007199254740995"]),
+            Ok(9007199254740994)
+        );
+        assert_eq!(
+            rates(
+                100_000_000_000_000,
+                &["67234.123456789012345677", "67234.123456789012345679"]
+            ),
+            Ok(6723412345678901234)
+        );
+        // A BTC rate at 1e18 precision
+        assert_eq!(
+            rates(1_000_000_000_000_000_000, &["67234.1"]),
+            Ok(67_234_100_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_output_rates() {
+        let rated = |rate| SymbolRate {
+            rate,
+            status: RateStatus::Ok,
+            rejected: ExchangeSet::default(),
+        };
+        let rates = [
+            rated(10100),
+            rated(67_234_100_000_000_000_000_000),
+            SymbolRate::unrated(RateStatus::NoData, ExchangeSet::default()),
+        ];
+        let requested = symbols(&["ETH", "BTC", "BAND"]);
+
+        assert_eq!(
+            output_rates::<u64>(&rates, &requested),
+            Err(ScriptError::RateOverflow("BTC".into()))
+        );
+        assert_eq!(
+            output_rates::<u128>(&rates, &requested),
+            Ok(vec![10100, 67_234_100_000_000_000_000_000, 0])
+        );
+        assert_eq!(
+            partial_output_rates::<u64>(&rates),
+            (vec![10100, 0, 0], vec![0, 5, 2])
+        );
+        assert_eq!(
+            partial_output_rates::<u128>(&rates),
+            (vec![10100, 67_234_100_000_000_000_000_000, 0], vec![0, 0, 2])
+        );
+    }
+
+    #[test]
+    fn test_aggregate_rates_errors() {
+        assert_eq!(
+            run(&["BAND"], &[(0, &["1,2"])]),
+            Err(ScriptError::NoPrice("BAND".into()))
+        );
+        assert_eq!(
+            run(&["BAND"], &[]),
+            Err(ScriptError::NoPrice("BAND".into()))
+        );
+    }
+}
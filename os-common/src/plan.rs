@@ -0,0 +1,144 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::error::ScriptError;
+use crate::price::{get_ds_from_exchange, get_ds_input, PriceScript};
+
+/// One raw request issued in the prepare phase of a price script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawRequest {
+    /// External ID of the request, which is also the exchange ID.
+    pub external_id: u64,
+    pub data_source_id: i64,
+    /// Canonical symbols asked for, sorted. Reports list prices in this
+    /// order.
+    pub symbols: Vec<&'static str>,
+    pub calldata: String,
+}
+
+/// The raw requests a price script issues for a list of requested symbols,
+/// sorted by external ID, with each request's symbols sorted too.
+///
+/// Prepare and execute both derive the plan from the input alone, so the
+/// order of raw requests and of intermediate results is reproducible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparePlan {
+    pub requests: Vec<RawRequest>,
+}
+
+impl PreparePlan {
+    pub fn build<S: PriceScript>(symbols: &[String]) -> Result<PreparePlan, ScriptError> {
+        if let Some(symbol) = symbols.iter().find(|symbol| S::lookup(symbol).is_none()) {
+            return Err(ScriptError::UnknownSymbol(symbol.clone()));
+        }
+        PreparePlan::build_partial::<S>(symbols)
+    }
+
+    /// Like [`PreparePlan::build`], but leaves out the symbols the script
+    /// does not serve rather than fail, unless it serves none of them.
+    pub fn build_partial<S: PriceScript>(symbols: &[String]) -> Result<PreparePlan, ScriptError> {
+        // A plan without any symbol would ask for nothing
+        if !symbols.is_empty() && symbols.iter().all(|symbol| S::lookup(symbol).is_none()) {
+            return Err(ScriptError::UnknownSymbol(symbols[0].clone()));
+        }
+        let mut exchange_symbols: BTreeMap<u64, BTreeSet<&'static str>> = BTreeMap::new();
+        for (name, info) in symbols.iter().filter_map(|symbol| S::lookup(symbol)) {
+            for exchange_id in info.exchanges.ids() {
+                exchange_symbols
+                    .entry(exchange_id)
+                    .or_default()
+                    .insert(name);
+            }
+        }
+
+        let requests = exchange_symbols
+            .into_iter()
+            .map(|(external_id, symbols)| {
+                let symbols: Vec<&'static str> = symbols.into_iter().collect();
+                Ok(RawRequest {
+                    external_id,
+                    data_source_id: get_ds_from_exchange::<S>(external_id)?,
+                    calldata: get_ds_input::<S>(external_id, &symbols)?,
+                    symbols,
+                })
+            })
+            .collect::<Result<_, ScriptError>>()?;
+        Ok(PreparePlan { requests })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::price::tests::{symbols, Script};
+
+    #[test]
+    fn test_build_orders_requests_and_symbols() {
+        let plan =
+            PreparePlan::build::<Script>(&symbols(&["ETH", "xbt", "BAND", "1INCH"])).unwrap();
+        assert_eq!(
+            plan.requests,
+            vec![
+                RawRequest {
+                    external_id: 0,
+                    data_source_id: 11,
+                    symbols: vec!["BAND", "BTC"],
+                    calldata: "BAND BTC".into(),
+                },
+                RawRequest {
+                    external_id: 1,
+                    data_source_id: 3,
+                    symbols: vec!["1INCH", "BTC", "ETH"],
+                    calldata: "binance 1INCH BTC ETH".into(),
+                },
+                RawRequest {
+                    external_id: 2,
+                    data_source_id: 12,
+                    symbols: vec!["BTC", "ETH"],
+                    calldata: "BTC ETH".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_ignores_request_order() {
+        assert_eq!(
+            PreparePlan::build::<Script>(&symbols(&["BTC", "ETH", "BAND"])),
+            PreparePlan::build::<Script>(&symbols(&["BAND", "ETH", "btc"]))
+        );
+    }
+
+    #[test]
+    fn test_build_asks_aliases_once() {
+        let plan = PreparePlan::build::<Script>(&symbols(&["XBT", "ETH", "btc", "BTC"])).unwrap();
+        let asked: Vec<(u64, Vec<&str>)> = plan
+            .requests
+            .into_iter()
+            .map(|request| (request.external_id, request.symbols))
+            .collect();
+        assert_eq!(
+            asked,
+            vec![
+                (0, vec!["BTC"]),
+                (1, vec!["BTC", "ETH"]),
+                (2, vec!["BTC", "ETH"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_unknown_symbol() {
+        assert_eq!(
+            PreparePlan::build::<Script>(&symbols(&["BTC", "DOGE"])),
+            Err(ScriptError::UnknownSymbol("DOGE".into()))
+        );
+        assert_eq!(
+            PreparePlan::build_partial::<Script>(&symbols(&["BTC", "DOGE"])),
+            PreparePlan::build::<Script>(&symbols(&["BTC"]))
+        );
+        assert_eq!(
+            PreparePlan::build_partial::<Script>(&symbols(&["DOGE", "SHIB"])),
+            Err(ScriptError::UnknownSymbol("DOGE".into()))
+        );
+    }
+}
@@ -0,0 +1,538 @@
+use strum::IntoEnumIterator;
+
+use crate::error::ScriptError;
+use crate::pipeline::Aggregation;
+use crate::symbol::{AliasMap, SymbolInfo, SymbolMap};
+
+/// Describes the symbol registry and exchange table of a price oracle script.
+///
+/// Exchange IDs are the positions of `Exchange` variants in declaration
+/// order, and they double as the external IDs of the raw requests.
+pub trait PriceScript {
+    /// The exchanges the script queries. Variant discriminants are the
+    /// exchange IDs.
+    type Exchange: Copy + PartialEq + ToString + IntoEnumIterator + 'static;
+
+    const EXCHANGE_COUNT: u64;
+    /// Exchanges with a data source of their own. Every other exchange is
+    /// queried through the CCXT data source.
+    const API_SOURCE: &'static [Self::Exchange];
+    /// Data source ID of CCXT, needed when some exchange is not an API
+    /// source.
+    const CCXT_DS_ID: Option<i64> = None;
+    /// The symbols the script is able to price.
+    const SYMBOLS: &'static SymbolMap;
+    /// Other spellings of symbols, keyed by uppercase spelling, e.g.
+    /// `RMB` => `CNY`.
+    const ALIASES: &'static AliasMap;
+    /// Tokens of the table that the script refuses to price, e.g. because
+    /// the network it is built for does not serve them.
+    const EXCLUDED_TOKENS: &'static [&'static str] = &[];
+    /// Symbols the network the script is built for prices from other
+    /// exchanges than `SYMBOLS` says, with their metadata on that network.
+    const COVERAGE: &'static [(&'static str, SymbolInfo)] = &[];
+    /// How the script combines prices. A script taking the strategies from
+    /// its `Input` instead parses them with [`Strategy`](crate::Strategy)'s
+    /// `FromStr`. Outliers are only rejected by scripts that opt in with
+    /// [`Aggregation::reject_outliers`].
+    const AGGREGATION: Aggregation = Aggregation::MEDIAN;
+
+    /// Resolves a requested spelling to its canonical symbol and metadata.
+    /// An exact match wins; otherwise the uppercase spelling is looked up in
+    /// `ALIASES`, then in `SYMBOLS`. The metadata is taken from `COVERAGE`
+    /// for the symbols it lists.
+    fn lookup(symbol: &str) -> Option<(&'static str, &'static SymbolInfo)> {
+        let (name, info) = Self::SYMBOLS.get_entry(symbol).or_else(|| {
+            let upper = symbol.to_ascii_uppercase();
+            match Self::ALIASES.get(upper.as_str()) {
+                Some(canonical) => Self::SYMBOLS.get_entry(canonical),
+                None => Self::SYMBOLS.get_entry(upper.as_str()),
+            }
+        })?;
+        if Self::EXCLUDED_TOKENS.contains(name) {
+            return None;
+        }
+        let info = match Self::COVERAGE.iter().find(|(symbol, _)| symbol == name) {
+            Some((_, overridden)) => overridden,
+            None => info,
+        };
+        Some((*name, info))
+    }
+
+    /// Returns the data source ID of an API source exchange, usually from
+    /// the network profile the script is built against.
+    fn data_source_id(exchange: Self::Exchange) -> Option<i64>;
+}
+
+pub fn exchange_from_u64<S: PriceScript>(value: u64) -> Option<S::Exchange> {
+    S::Exchange::iter().nth(value as usize)
+}
+
+fn get_exchange<S: PriceScript>(exchange_id: u64) -> Result<S::Exchange, ScriptError> {
+    exchange_from_u64::<S>(exchange_id).ok_or(ScriptError::UnknownExchange(exchange_id))
+}
+
+/// Builds the calldata sent to an exchange's data source. CCXT calldata is
+/// prefixed with the lowercase exchange name.
+pub fn get_ds_input<S: PriceScript>(
+    exchange_id: u64,
+    symbols: &[&str],
+) -> Result<String, ScriptError> {
+    let exchange = get_exchange::<S>(exchange_id)?;
+    let symbols = symbols.join(" ");
+    if S::API_SOURCE.contains(&exchange) {
+        Ok(symbols)
+    } else {
+        Ok(format!(
+            "{} {}",
+            exchange.to_string().to_ascii_lowercase(),
+            symbols
+        ))
+    }
+}
+
+pub fn get_ds_from_exchange<S: PriceScript>(exchange_id: u64) -> Result<i64, ScriptError> {
+    let exchange = get_exchange::<S>(exchange_id)?;
+    let id = match S::API_SOURCE.contains(&exchange) {
+        true => S::data_source_id(exchange),
+        false => S::CCXT_DS_ID,
+    };
+    id.ok_or_else(|| ScriptError::MissingDataSource(exchange.to_string()))
+}
+
+/// Recovers the requested symbols from calldata built by [`get_ds_input`].
+pub fn get_symbols_from_input<S: PriceScript>(
+    exchange_id: u64,
+    input: &str,
+) -> Result<Vec<String>, ScriptError> {
+    let exchange = get_exchange::<S>(exchange_id)?;
+    let mut v: Vec<String> = input.split(' ').map(|x| x.to_string()).collect();
+    if !S::API_SOURCE.contains(&exchange) {
+        v.drain(0..1);
+    }
+    Ok(v)
+}
+
+/// Returns the canonical spelling of a requested symbol.
+pub fn canonical_symbol<S: PriceScript>(symbol: &str) -> Result<&'static str, ScriptError> {
+    S::lookup(symbol)
+        .map(|(name, _)| name)
+        .ok_or_else(|| ScriptError::UnknownSymbol(symbol.to_string()))
+}
+
+/// Defines the `Input`, `Output` and `PartialOutput` of a price script, its
+/// `schema()`, and the prepare and execute entry points of its default and
+/// `partial` builds, all served by the given [`PriceScript`]:
+///
+/// ```ignore
+/// struct Script;
+///
+/// impl PriceScript for Script { ... }
+///
+/// os_common::price_script!(Script);
+/// ```
+///
+/// Rates are encoded as `u64`s, and a rate that does not fit fails with
+/// [`ScriptError::RateOverflow`](crate::ScriptError::RateOverflow). The
+/// `wide` build encodes them as `u128`s instead, so that rates at a
+/// multiplier as large as 1e18 stay exact; it is registered on schemas of
+/// its own, `<script>+wide` and `<script>+partial+wide`.
+///
+/// `price_script!(Script, rejected_exchanges)` also lists the exchanges
+/// rejected as outliers in both outputs. It is for the scripts whose
+/// [`PriceScript::AGGREGATION`] rejects outliers, and only for them: a
+/// script whose outputs disagree with its aggregation does not compile.
+///
+/// Under `cfg(test)` both builds are exported, the `partial` one as
+/// `prepare_partial` and `execute_partial`, and tests replay the fixtures
+/// naming either build and check both schemas against schemas.json.
+#[macro_export]
+macro_rules! price_script {
+    (@outputs $script:ident { $($(#[$doc:meta])* $field:ident: $ty:ty = $from_rates:path,)* }) => {
+        #[derive(::obi::OBIDecode, ::obi::OBISchema)]
+        #[cfg_attr(test, derive(::obi::OBIEncode))]
+        struct Input {
+            symbols: Vec<String>,
+            multiplier: u64,
+        }
+
+        // The integers rates are encoded as, see price_script!
+        #[cfg(not(feature = "wide"))]
+        type Rate = u64;
+        #[cfg(feature = "wide")]
+        type Rate = u128;
+
+        #[derive(::obi::OBIEncode, ::obi::OBISchema)]
+        #[cfg_attr(test, derive(::obi::OBIDecode))]
+        struct Output<R> {
+            rates: Vec<R>,
+            $($(#[$doc])* $field: $ty,)*
+        }
+
+        /// Output of the `partial` build, in which a symbol without a rate
+        /// does not fail the request.
+        #[derive(::obi::OBIEncode, ::obi::OBISchema)]
+        #[cfg_attr(test, derive(::obi::OBIDecode))]
+        struct PartialOutput<R> {
+            rates: Vec<R>,
+            /// Status code of each rate, see [`os_common::RateStatus`]. A rate
+            /// is 0 unless its status is 0.
+            statuses: Vec<u8>,
+            $($(#[$doc])* $field: $ty,)*
+        }
+
+        /// The on-chain `{input}/{output}` schema of this build, recorded in
+        /// schemas.json.
+        pub fn schema() -> String {
+            match cfg!(feature = "partial") {
+                true => $crate::obi_schema::<Input, PartialOutput<Rate>>(),
+                false => $crate::obi_schema::<Input, Output<Rate>>(),
+            }
+        }
+
+        fn ask(plan: $crate::PreparePlan) {
+            for request in plan.requests {
+                $crate::host::oei::ask_external_data(
+                    request.external_id as i64,
+                    request.data_source_id,
+                    request.calldata.as_bytes(),
+                )
+            }
+        }
+
+        fn load_reports(eid: i64) -> Vec<String> {
+            $crate::host::ext::load_input::<String>(eid).collect()
+        }
+
+        #[cfg_attr(feature = "partial", allow(dead_code))]
+        fn prepare_impl(input: Input) -> Result<(), $crate::ScriptError> {
+            ask($crate::PreparePlan::build::<$script>(&input.symbols)?);
+            Ok(())
+        }
+
+        #[cfg_attr(feature = "partial", allow(dead_code))]
+        fn execute_impl(input: Input) -> Result<Output<Rate>, $crate::ScriptError> {
+            // Rebuild the plan prepare asked for; reports are read back in its
+            // order
+            let plan = $crate::PreparePlan::build::<$script>(&input.symbols)?;
+            let rates = $crate::aggregate_rates::<$script>(
+                &plan,
+                &input.symbols,
+                input.multiplier,
+                &<$script as $crate::PriceScript>::AGGREGATION,
+                load_reports,
+            )?;
+            Ok(Output {
+                rates: $crate::output_rates(&rates, &input.symbols)?,
+                $($field: $from_rates(&rates),)*
+            })
+        }
+
+        // Leaves out the symbols the script does not serve, unless it serves
+        // none
+        #[cfg_attr(not(feature = "partial"), allow(dead_code))]
+        fn prepare_partial_impl(input: Input) -> Result<(), $crate::ScriptError> {
+            ask($crate::PreparePlan::build_partial::<$script>(&input.symbols)?);
+            Ok(())
+        }
+
+        #[cfg_attr(not(feature = "partial"), allow(dead_code))]
+        fn execute_partial_impl(
+            input: Input,
+        ) -> Result<PartialOutput<Rate>, $crate::ScriptError> {
+            let plan = $crate::PreparePlan::build_partial::<$script>(&input.symbols)?;
+            let rates = $crate::aggregate_partial_rates::<$script>(
+                &plan,
+                &input.symbols,
+                input.multiplier,
+                &<$script as $crate::PriceScript>::AGGREGATION,
+                load_reports,
+            )?;
+            let (encoded, statuses) = $crate::partial_output_rates(&rates);
+            Ok(PartialOutput {
+                rates: encoded,
+                statuses,
+                $($field: $from_rates(&rates),)*
+            })
+        }
+
+        #[cfg(any(test, not(feature = "partial")))]
+        $crate::prepare_entry_point!(prepare_impl);
+        #[cfg(any(test, not(feature = "partial")))]
+        $crate::execute_entry_point!(execute_impl);
+        #[cfg(all(feature = "partial", not(test)))]
+        $crate::prepare_entry_point!(prepare_partial_impl);
+        #[cfg(all(feature = "partial", not(test)))]
+        $crate::execute_entry_point!(execute_partial_impl);
+        #[cfg(test)]
+        $crate::prepare_entry_point!(prepare_partial_impl as prepare_partial);
+        #[cfg(test)]
+        $crate::execute_entry_point!(execute_partial_impl as execute_partial);
+
+        #[cfg(test)]
+        mod price_script_tests {
+            use super::*;
+
+            #[test]
+            fn test_fixtures() {
+                let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures");
+                let wide = match cfg!(feature = "wide") {
+                    true => "+wide",
+                    false => "",
+                };
+                $crate::host::replay::replay_fixtures(
+                    fixtures,
+                    &format!("{}{}", env!("CARGO_PKG_NAME"), wide),
+                    prepare,
+                    execute,
+                );
+                $crate::host::replay::replay_fixtures(
+                    fixtures,
+                    &format!("{}+partial{}", env!("CARGO_PKG_NAME"), wide),
+                    prepare_partial,
+                    execute_partial,
+                );
+            }
+
+            #[test]
+            fn test_schema() {
+                let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/../../schemas.json");
+                let schemas = [
+                    ("", $crate::obi_schema::<Input, Output<u64>>()),
+                    ("+partial", $crate::obi_schema::<Input, PartialOutput<u64>>()),
+                    ("+wide", $crate::obi_schema::<Input, Output<u128>>()),
+                    (
+                        "+partial+wide",
+                        $crate::obi_schema::<Input, PartialOutput<u128>>(),
+                    ),
+                ];
+                for (features, schema) in schemas {
+                    let variant = format!("{}{}", env!("CARGO_PKG_NAME"), features);
+                    $crate::schema::check_schema(manifest, &variant, &schema);
+                }
+            }
+        }
+    };
+    ($script:ident) => {
+        const _: () = assert!(
+            <$script as $crate::PriceScript>::AGGREGATION
+                .outlier_cutoff
+                .is_none(),
+            "the aggregation rejects outliers, see price_script!(_, rejected_exchanges)",
+        );
+        $crate::price_script!(@outputs $script {});
+    };
+    ($script:ident, rejected_exchanges) => {
+        const _: () = assert!(
+            <$script as $crate::PriceScript>::AGGREGATION
+                .outlier_cutoff
+                .is_some(),
+            "the aggregation rejects no outliers to list",
+        );
+        $crate::price_script!(@outputs $script {
+            /// Exchanges rejected as outliers, for the rates that had any.
+            rejected_exchanges: Vec<$crate::RejectedExchanges> = $crate::rejected_exchanges,
+        });
+    };
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::exchanges;
+    use phf::phf_map;
+    use strum_macros::{Display, EnumIter, EnumString};
+
+    #[derive(Display, EnumString, EnumIter, Debug, Copy, Clone, PartialEq)]
+    pub(crate) enum Exchange {
+        COINGECKO = 0,
+        BINANCE = 1,
+        CRYPTOCOMPARE = 2,
+    }
+
+    fn data_source_id(exchange: Exchange) -> Option<i64> {
+        match exchange {
+            Exchange::COINGECKO => Some(11),
+            Exchange::CRYPTOCOMPARE => Some(12),
+            Exchange::BINANCE => None,
+        }
+    }
+
+    static SYMBOLS: SymbolMap = phf_map! {
+        "BTC" => SymbolInfo { exchanges: exchanges![COINGECKO, BINANCE, CRYPTOCOMPARE], min_sources: 1 },
+        "ETH" => SymbolInfo { exchanges: exchanges![BINANCE, CRYPTOCOMPARE], min_sources: 1 },
+        "BAND" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+        "1INCH" => SymbolInfo { exchanges: exchanges![BINANCE], min_sources: 1 },
+        "DOT" => SymbolInfo { exchanges: exchanges![COINGECKO, BINANCE, CRYPTOCOMPARE], min_sources: 2 },
+        "Psi" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+    };
+
+    static ALIASES: AliasMap = phf_map! {
+        "PSI" => "Psi",
+        "XBT" => "BTC",
+    };
+
+    pub(crate) struct Script;
+
+    impl PriceScript for Script {
+        type Exchange = Exchange;
+
+        const EXCHANGE_COUNT: u64 = 3;
+        const API_SOURCE: &'static [Exchange] = &[Exchange::COINGECKO, Exchange::CRYPTOCOMPARE];
+        const SYMBOLS: &'static SymbolMap = &SYMBOLS;
+        const ALIASES: &'static AliasMap = &ALIASES;
+        const CCXT_DS_ID: Option<i64> = Some(3);
+
+        fn data_source_id(exchange: Exchange) -> Option<i64> {
+            data_source_id(exchange)
+        }
+    }
+
+    pub(crate) fn symbols(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_exchange_from_u64() {
+        assert_eq!(exchange_from_u64::<Script>(1), Some(Exchange::BINANCE));
+        assert_eq!(exchange_from_u64::<Script>(3), None);
+    }
+
+    struct Excluding;
+
+    impl PriceScript for Excluding {
+        type Exchange = Exchange;
+
+        const EXCHANGE_COUNT: u64 = Script::EXCHANGE_COUNT;
+        const API_SOURCE: &'static [Exchange] = Script::API_SOURCE;
+        const SYMBOLS: &'static SymbolMap = &SYMBOLS;
+        const ALIASES: &'static AliasMap = &ALIASES;
+        const EXCLUDED_TOKENS: &'static [&'static str] = &["BAND"];
+
+        fn data_source_id(exchange: Exchange) -> Option<i64> {
+            data_source_id(exchange)
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(Script::lookup("1INCH").map(|(name, _)| name), Some("1INCH"));
+    }
+
+    #[test]
+    fn test_lookup_canonicalizes() {
+        for (requested, canonical) in [
+            ("btc", "BTC"),
+            ("xbt", "BTC"),
+            ("XBT", "BTC"),
+            ("Psi", "Psi"),
+            ("PSI", "Psi"),
+            ("psi", "Psi"),
+        ] {
+            assert_eq!(canonical_symbol::<Script>(requested), Ok(canonical));
+        }
+        assert_eq!(
+            canonical_symbol::<Script>("DOGE"),
+            Err(ScriptError::UnknownSymbol("DOGE".into()))
+        );
+    }
+
+    struct Overriding;
+
+    impl PriceScript for Overriding {
+        type Exchange = Exchange;
+
+        const EXCHANGE_COUNT: u64 = Script::EXCHANGE_COUNT;
+        const API_SOURCE: &'static [Exchange] = Script::API_SOURCE;
+        const SYMBOLS: &'static SymbolMap = &SYMBOLS;
+        const ALIASES: &'static AliasMap = &ALIASES;
+        const COVERAGE: &'static [(&'static str, SymbolInfo)] = &[(
+            "ETH",
+            SymbolInfo {
+                exchanges: exchanges![COINGECKO],
+                min_sources: 1,
+            },
+        )];
+
+        fn data_source_id(exchange: Exchange) -> Option<i64> {
+            data_source_id(exchange)
+        }
+    }
+
+    #[test]
+    fn test_coverage_overrides() {
+        let (name, info) = Overriding::lookup("eth").unwrap();
+        assert_eq!(name, "ETH");
+        assert_eq!(info.exchanges, exchanges![COINGECKO]);
+        assert_eq!(Overriding::lookup("BTC"), Script::lookup("BTC"));
+    }
+
+    #[test]
+    fn test_excluded_tokens() {
+        assert!(Excluding::lookup("BTC").is_some());
+        assert!(Excluding::lookup("BAND").is_none());
+        assert!(Excluding::lookup("band").is_none());
+        assert!(Script::lookup("BAND").is_some());
+    }
+
+    #[test]
+    fn test_get_ds_input() {
+        let tokens = ["BTC", "ETH"];
+        assert_eq!(get_ds_input::<Script>(0, &tokens).unwrap(), "BTC ETH");
+        assert_eq!(
+            get_ds_input::<Script>(1, &tokens).unwrap(),
+            "binance BTC ETH"
+        );
+    }
+
+    #[test]
+    fn test_get_ds_from_exchange() {
+        assert_eq!(get_ds_from_exchange::<Script>(0), Ok(11));
+        assert_eq!(get_ds_from_exchange::<Script>(1), Ok(3));
+        assert_eq!(get_ds_from_exchange::<Script>(2), Ok(12));
+    }
+
+    struct WithoutCcxt;
+
+    impl PriceScript for WithoutCcxt {
+        type Exchange = Exchange;
+
+        const EXCHANGE_COUNT: u64 = Script::EXCHANGE_COUNT;
+        const API_SOURCE: &'static [Exchange] = Script::API_SOURCE;
+        const SYMBOLS: &'static SymbolMap = &SYMBOLS;
+        const ALIASES: &'static AliasMap = &ALIASES;
+
+        fn data_source_id(exchange: Exchange) -> Option<i64> {
+            data_source_id(exchange)
+        }
+    }
+
+    #[test]
+    fn test_get_ds_from_exchange_missing() {
+        assert_eq!(get_ds_from_exchange::<WithoutCcxt>(0), Ok(11));
+        assert_eq!(
+            get_ds_from_exchange::<WithoutCcxt>(1),
+            Err(ScriptError::MissingDataSource("BINANCE".into()))
+        );
+    }
+
+    #[test]
+    fn test_get_ds_from_exchange_unknown() {
+        assert_eq!(
+            get_ds_from_exchange::<Script>(3),
+            Err(ScriptError::UnknownExchange(3))
+        );
+    }
+
+    #[test]
+    fn test_get_symbols_from_input_round_trip() {
+        let tokens = ["BTC", "ETH"];
+        for exchange_id in 0..3 {
+            let input = get_ds_input::<Script>(exchange_id, &tokens).unwrap();
+            assert_eq!(
+                get_symbols_from_input::<Script>(exchange_id, &input),
+                Ok(symbols(&["BTC", "ETH"]))
+            );
+        }
+    }
+}
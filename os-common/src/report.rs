@@ -0,0 +1,152 @@
+//! Parsers of the raw reports data sources send back.
+//!
+//! A parser either returns every field of a report, in report order, or
+//! rejects the whole report. It never drops a field it cannot read, which
+//! would shift the fields after it onto the wrong symbol, and never panics
+//! on what a validator sends. `fuzz/` holds a libFuzzer target for each,
+//! run with `cargo +nightly fuzz run <parser>` from `os-common`.
+
+use crate::decimal::Decimal;
+
+/// Hex characters of the ECVRF proof at the start of a VRF report.
+pub const VRF_PROOF_LEN: usize = 160;
+/// Hex characters of a VRF report: the proof, then the 64-byte VRF output.
+pub const VRF_REPORT_LEN: usize = 288;
+
+/// Field of a price report standing for a price the data source could not
+/// get.
+pub const MISSING_PRICE: &str = "-";
+
+/// Parses a comma-separated list of exactly `count` prices, e.g. `1.5,-,100`.
+/// Every field must be a [`Decimal`], or [`MISSING_PRICE`], which leaves its
+/// slot empty. A price of 0, or one too small to hold, leaves its slot empty
+/// too: the source has no price for that symbol, but its other fields stand.
+pub fn parse_prices(report: &str, count: usize) -> Option<Vec<Option<Decimal>>> {
+    let prices = report
+        .split(',')
+        .map(|field| match field {
+            MISSING_PRICE => Some(None),
+            _ => field
+                .parse::<Decimal>()
+                .ok()
+                .map(|px| (px > Decimal::ZERO).then_some(px)),
+        })
+        .collect::<Option<Vec<Option<Decimal>>>>()?;
+    (prices.len() == count).then_some(prices)
+}
+
+/// Parses exactly `count` whitespace-separated integers, e.g. `104 12`.
+pub fn parse_integers(report: &str, count: usize) -> Option<Vec<u64>> {
+    let values = report
+        .split_whitespace()
+        .map(|field| match field.starts_with('+') {
+            true => None,
+            false => field.parse().ok(),
+        })
+        .collect::<Option<Vec<u64>>>()?;
+    (values.len() == count).then_some(values)
+}
+
+/// Parses comma-separated `key:value` pairs, e.g. `GDP:21000,CPI:3`. Keys
+/// are non-empty and values are integers.
+pub fn parse_key_values(report: &str) -> Option<Vec<(String, u64)>> {
+    report
+        .split(',')
+        .map(|pair| {
+            let (key, value) = pair.split_once(':')?;
+            if key.is_empty() || value.starts_with('+') {
+                return None;
+            }
+            Some((key.to_string(), value.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Splits a VRF report of [`VRF_REPORT_LEN`] hex characters into the
+/// decoded proof and VRF output.
+pub fn parse_vrf_report(report: &str) -> Option<(Vec<u8>, Vec<u8>)> {
+    let report = report.as_bytes();
+    if report.len() != VRF_REPORT_LEN {
+        return None;
+    }
+    let (proof, output) = report.split_at(VRF_PROOF_LEN);
+    Some((hex::decode(proof).ok()?, hex::decode(output).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn px(s: &str) -> Option<Decimal> {
+        Some(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_parse_prices() {
+        assert_eq!(parse_prices("1.5,100", 2), Some(vec![px("1.5"), px("100")]));
+        assert_eq!(parse_prices("1.5", 1), Some(vec![px("1.5")]));
+        assert_eq!(
+            parse_prices("1.5,-,100", 3),
+            Some(vec![px("1.5"), None, px("100")])
+        );
+        assert_eq!(parse_prices("-", 1), Some(vec![None]));
+        assert_eq!(parse_prices("1.5,100", 3), None);
+        assert_eq!(parse_prices("1.5,100", 1), None);
+        assert_eq!(parse_prices("1.5,abc,100", 3), None);
+        assert_eq!(parse_prices("1.5,,100", 3), None);
+        assert_eq!(parse_prices("1.5,--,100", 3), None);
+        assert_eq!(parse_prices("", 1), None);
+        assert_eq!(parse_prices("NaN", 1), None);
+        assert_eq!(parse_prices("inf", 1), None);
+        assert_eq!(parse_prices("-1", 1), None);
+        assert_eq!(parse_prices("-0", 1), None);
+        // A price of 0 is missing, and the other fields still count
+        assert_eq!(parse_prices("0", 1), Some(vec![None]));
+        assert_eq!(
+            parse_prices("1.5,0,100", 3),
+            Some(vec![px("1.5"), None, px("100")])
+        );
+        // Too small to hold, so 0
+        assert_eq!(
+            parse_prices("0.0000000000000000001,2", 2),
+            Some(vec![None, px("2")])
+        );
+    }
+
+    #[test]
+    fn test_parse_integers() {
+        assert_eq!(parse_integers("104 12", 2), Some(vec![104, 12]));
+        assert_eq!(parse_integers(" 104\t12 ", 2), Some(vec![104, 12]));
+        assert_eq!(parse_integers("104 x 12", 2), None);
+        assert_eq!(parse_integers("104 +12", 2), None);
+        assert_eq!(parse_integers("104 12", 3), None);
+        assert_eq!(parse_integers("104 -12", 2), None);
+    }
+
+    #[test]
+    fn test_parse_key_values() {
+        assert_eq!(
+            parse_key_values("GDP:21000,CPI:3"),
+            Some(vec![("GDP".into(), 21000), ("CPI".into(), 3)])
+        );
+        assert_eq!(parse_key_values("GDP:21000,CPI:3.5"), None);
+        assert_eq!(parse_key_values("GDP:21000,CPI"), None);
+        assert_eq!(parse_key_values("GDP:1:2"), None);
+        assert_eq!(parse_key_values(":1"), None);
+    }
+
+    #[test]
+    fn test_parse_vrf_report() {
+        let report = format!("{}{}", "ab".repeat(80), "cd".repeat(64));
+        assert_eq!(
+            parse_vrf_report(&report),
+            Some((vec![0xab; 80], vec![0xcd; 64]))
+        );
+        assert_eq!(parse_vrf_report(&report[1..]), None);
+        assert_eq!(parse_vrf_report(&report.replacen("ab", "+1", 1)), None);
+        // A multi-byte character across the proof boundary
+        let report = format!("{}aé{}", "ab".repeat(79), "c".repeat(127));
+        assert_eq!(report.len(), VRF_REPORT_LEN);
+        assert_eq!(parse_vrf_report(&report), None);
+    }
+}
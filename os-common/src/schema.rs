@@ -0,0 +1,127 @@
+//! OBI schema strings of the scripts' `Input` and `Output`, and the
+//! `schemas.json` manifest that records them.
+//!
+//! The schema is what an oracle script is registered with on chain, so a
+//! struct change that alters it needs a new registration. Each script checks
+//! its schema against the manifest with [`check_schema`] from one of its
+//! tests; run `UPDATE_SCHEMAS=1 cargo test --workspace` to regenerate the
+//! manifest after an intended change.
+//!
+//! A build of a script with a cargo feature that changes its schema, e.g.
+//! the `partial` build of the price scripts, is registered on its own and
+//! recorded as `<script>+<feature>`.
+
+use obi::schema::{Declaration, Definition};
+use obi::OBISchema;
+use std::collections::HashMap;
+
+/// Name of the schema manifest at the repository root.
+pub const SCHEMAS_FILE: &str = "schemas.json";
+
+/// Returns the `{input}/{output}` schema string of a script.
+pub fn obi_schema<I: OBISchema, O: OBISchema>() -> String {
+    let mut definitions = HashMap::new();
+    I::add_definitions_recursively(&mut definitions);
+    O::add_definitions_recursively(&mut definitions);
+    format!(
+        "{}/{}",
+        render(&I::declaration(), &definitions),
+        render(&O::declaration(), &definitions)
+    )
+}
+
+// `obi::get_schema` writes sequences as `[string]:[string]`, which is not
+// what BandChain registers, so schemas are rendered from the definitions.
+fn render(declaration: &Declaration, definitions: &HashMap<Declaration, Definition>) -> String {
+    match definitions.get(declaration) {
+        Some(Definition::Sequence { elements }) if elements == "u8" => "bytes".into(),
+        Some(Definition::Sequence { elements }) => format!("[{}]", render(elements, definitions)),
+        Some(Definition::Struct { fields }) => {
+            let fields: Vec<String> = fields
+                .iter()
+                .map(|(name, field)| format!("{}:{}", name, render(field, definitions)))
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        None => declaration.clone(),
+    }
+}
+
+/// Script name to schema string, as stored in the manifest.
+#[cfg(feature = "mock")]
+pub type Manifest = std::collections::BTreeMap<String, String>;
+
+#[cfg(feature = "mock")]
+pub fn load_manifest(path: &std::path::Path) -> Result<Manifest, String> {
+    let raw =
+        std::fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+    serde_json::from_str(&raw).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+/// Panics unless the manifest at `path` records `schema` for `script`. With
+/// `UPDATE_SCHEMAS` set in the environment it records it instead.
+#[cfg(feature = "mock")]
+pub fn check_schema(path: &str, script: &str, schema: &str) {
+    let path = std::path::Path::new(path);
+    let mut manifest = load_manifest(path).unwrap_or_else(|err| panic!("{}", err));
+    if std::env::var_os("UPDATE_SCHEMAS").is_some() {
+        manifest.insert(script.to_string(), schema.to_string());
+        let raw = serde_json::to_string_pretty(&manifest).unwrap() + "\n";
+        std::fs::write(path, raw).unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+        return;
+    }
+    assert_eq!(
+        manifest.get(script).map(String::as_str),
+        Some(schema),
+        "the schema of {} differs from {}; if the change is intended, \
+         register the script anew and run with UPDATE_SCHEMAS=1",
+        script,
+        SCHEMAS_FILE
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use obi::OBISchema;
+
+    #[derive(OBISchema)]
+    #[allow(dead_code)]
+    struct Input {
+        symbols: Vec<String>,
+        multiplier: u64,
+    }
+
+    #[derive(OBISchema)]
+    #[allow(dead_code)]
+    struct Output {
+        rates: Vec<u64>,
+        proof: Vec<u8>,
+    }
+
+    #[test]
+    fn test_obi_schema() {
+        assert_eq!(
+            obi_schema::<Input, Output>(),
+            "{symbols:[string],multiplier:u64}/{rates:[u64],proof:bytes}"
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn test_manifest_names_scripts() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for script in load_manifest(&root.join(SCHEMAS_FILE)).unwrap().keys() {
+            let crate_name = script.split('+').next().unwrap();
+            assert!(
+                root.join("scripts")
+                    .join(crate_name)
+                    .join("Cargo.toml")
+                    .is_file(),
+                "{} names unknown script {}",
+                SCHEMAS_FILE,
+                script
+            );
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::decimal::Decimal;
+
+/// A value the aggregation strategies combine: prices, or integer rates.
+pub trait Sample: Copy + Ord {
+    /// The mean of a non-empty list of values.
+    fn mean(values: &[Self]) -> Self;
+    /// How far apart two values are.
+    fn distance(self, other: Self) -> Self;
+    /// The value times `factor`, saturating if the type is bounded.
+    fn times(self, factor: Self) -> Self;
+}
+
+impl Sample for u64 {
+    /// Rounds down.
+    fn mean(values: &[u64]) -> u64 {
+        let sum: u128 = values.iter().map(|&v| v as u128).sum();
+        (sum / values.len() as u128) as u64
+    }
+
+    fn distance(self, other: u64) -> u64 {
+        self.abs_diff(other)
+    }
+
+    fn times(self, factor: u64) -> u64 {
+        self.saturating_mul(factor)
+    }
+}
+
+impl Sample for Decimal {
+    /// Rounds down, without ever overflowing.
+    fn mean(values: &[Decimal]) -> Decimal {
+        let count = values.len() as u128;
+        // floor(sum / count), summing quotients and remainders apart
+        let quotients: u128 = values.iter().map(|v| v.units() / count).sum();
+        let remainders: u128 = values.iter().map(|v| v.units() % count).sum();
+        Decimal::from_units(quotients + remainders / count)
+    }
+
+    fn distance(self, other: Decimal) -> Decimal {
+        self.abs_diff(other)
+    }
+
+    fn times(self, factor: Decimal) -> Decimal {
+        self.saturating_mul(factor)
+    }
+}
+
+/// How one stage of aggregation combines its values into one.
+///
+/// Only [`Strategy::Median`] and [`Strategy::TrimmedMean`] can produce a
+/// value that is not one of the inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// The middle value, or the mean of the two middle values of an even
+    /// count.
+    Median,
+    /// The lower of the two middle values of an even count.
+    LowerMedian,
+    /// The upper of the two middle values of an even count.
+    UpperMedian,
+    /// The mean of the values left after dropping `percent` percent of the
+    /// count, rounded down, from each end. At least one value is kept.
+    TrimmedMean { percent: u8 },
+    /// The lowest value at which the weights of it and the values below it
+    /// reach half the total weight.
+    WeightedMedian,
+}
+
+impl Strategy {
+    /// Combines `(value, weight)` pairs, sorting them in place. Weights are
+    /// only read by [`Strategy::WeightedMedian`]. Returns `None` for an
+    /// empty list.
+    pub fn aggregate<T: Sample>(&self, samples: &mut [(T, u64)]) -> Option<T> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_by_key(|sample| sample.0);
+        let len = samples.len();
+        let value = match self {
+            Strategy::Median => match len % 2 {
+                1 => samples[len / 2].0,
+                _ => T::mean(&[samples[len / 2 - 1].0, samples[len / 2].0]),
+            },
+            Strategy::LowerMedian => samples[(len - 1) / 2].0,
+            Strategy::UpperMedian => samples[len / 2].0,
+            Strategy::TrimmedMean { percent } => {
+                let trim = (len * *percent as usize / 100).min((len - 1) / 2);
+                let kept: Vec<T> = samples[trim..len - trim].iter().map(|s| s.0).collect();
+                T::mean(&kept)
+            }
+            Strategy::WeightedMedian => {
+                let total: u128 = samples.iter().map(|s| s.1 as u128).sum();
+                let mut cumulative = 0u128;
+                samples
+                    .iter()
+                    .find(|s| {
+                        cumulative += s.1 as u128;
+                        cumulative * 2 >= total
+                    })
+                    .unwrap_or(&samples[len - 1])
+                    .0
+            }
+        };
+        Some(value)
+    }
+
+    /// Combines values of equal weight, sorting them in place.
+    pub fn aggregate_values<T: Sample>(&self, values: &mut [T]) -> Option<T> {
+        let mut samples: Vec<(T, u64)> = values.iter().map(|&v| (v, 1)).collect();
+        let value = self.aggregate(&mut samples);
+        for (value, sample) in values.iter_mut().zip(samples) {
+            *value = sample.0;
+        }
+        value
+    }
+}
+
+/// Marks the values farther than `cutoff` times the median absolute
+/// deviation from the median of `values`. With a cutoff of at least 1, fewer
+/// than half the values are marked.
+///
+/// The deviation is taken as at least `floor` times the median, so when more
+/// than half the values are equal, a value close to them is not marked just
+/// because the deviation is 0.
+///
+/// Fewer than three values are never marked: with two, there is no telling
+/// which one is off.
+pub fn mad_outliers<T: Sample>(values: &[T], cutoff: T, floor: T) -> Vec<bool> {
+    let center = match values.len() {
+        0..=2 => return vec![false; values.len()],
+        _ => Strategy::Median
+            .aggregate_values(&mut values.to_vec())
+            .unwrap(),
+    };
+    let deviations: Vec<T> = values.iter().map(|v| v.distance(center)).collect();
+    let mad = Strategy::Median
+        .aggregate_values(&mut deviations.clone())
+        .unwrap();
+    let min_mad = center.times(floor);
+    let mad = match mad < min_mad {
+        true => min_mad,
+        false => mad,
+    };
+    let limit = mad.times(cutoff);
+    deviations.iter().map(|d| *d > limit).collect()
+}
+
+/// Spelled `median`, `lower_median`, `upper_median`, `weighted_median` or
+/// `trimmed_mean:<percent>`, e.g. for an `Input` field.
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Strategy, String> {
+        match s {
+            "median" => Ok(Strategy::Median),
+            "lower_median" => Ok(Strategy::LowerMedian),
+            "upper_median" => Ok(Strategy::UpperMedian),
+            "weighted_median" => Ok(Strategy::WeightedMedian),
+            _ => match s.strip_prefix("trimmed_mean:").map(u8::from_str) {
+                Some(Ok(percent)) if percent < 50 => Ok(Strategy::TrimmedMean { percent }),
+                _ => Err(format!("unknown aggregation strategy {:?}", s)),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Strategy::Median => write!(f, "median"),
+            Strategy::LowerMedian => write!(f, "lower_median"),
+            Strategy::UpperMedian => write!(f, "upper_median"),
+            Strategy::TrimmedMean { percent } => write!(f, "trimmed_mean:{}", percent),
+            Strategy::WeightedMedian => write!(f, "weighted_median"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn aggregate(strategy: Strategy, samples: &[(&str, u64)]) -> Option<Decimal> {
+        let mut samples: Vec<(Decimal, u64)> = samples
+            .iter()
+            .map(|&(value, weight)| (decimal(value), weight))
+            .collect();
+        strategy.aggregate(&mut samples)
+    }
+
+    #[test]
+    fn test_strategies() {
+        let samples = [("4", 1), ("1", 1), ("3", 1), ("2", 1)];
+        assert_eq!(aggregate(Strategy::Median, &samples), Some(decimal("2.5")));
+        assert_eq!(
+            aggregate(Strategy::LowerMedian, &samples),
+            Some(decimal("2"))
+        );
+        assert_eq!(
+            aggregate(Strategy::UpperMedian, &samples),
+            Some(decimal("3"))
+        );
+        assert_eq!(
+            aggregate(Strategy::WeightedMedian, &samples),
+            Some(decimal("2"))
+        );
+        assert_eq!(
+            aggregate(Strategy::TrimmedMean { percent: 0 }, &samples),
+            Some(decimal("2.5"))
+        );
+        assert_eq!(
+            aggregate(Strategy::Median, &[("3", 1), ("1", 1), ("2", 1)]),
+            Some(decimal("2"))
+        );
+        for strategy in [
+            Strategy::Median,
+            Strategy::LowerMedian,
+            Strategy::UpperMedian,
+        ] {
+            assert_eq!(aggregate(strategy, &[("7", 1)]), Some(decimal("7")));
+            assert_eq!(aggregate(strategy, &[]), None);
+        }
+    }
+
+    #[test]
+    fn test_trimmed_mean() {
+        let samples = [("1", 1), ("2", 1), ("3", 1), ("4", 1), ("100", 1)];
+        let trimmed = |percent| aggregate(Strategy::TrimmedMean { percent }, &samples);
+        assert_eq!(trimmed(0), Some(decimal("22")));
+        // 5 * 19 / 100 rounds down to nothing trimmed
+        assert_eq!(trimmed(19), Some(decimal("22")));
+        assert_eq!(trimmed(20), Some(decimal("3")));
+        assert_eq!(trimmed(49), Some(decimal("3")));
+        assert_eq!(
+            aggregate(Strategy::TrimmedMean { percent: 49 }, &[("1", 1), ("2", 1)]),
+            Some(decimal("1.5"))
+        );
+    }
+
+    #[test]
+    fn test_weighted_median() {
+        let weighted = |samples: &[(&str, u64)]| aggregate(Strategy::WeightedMedian, samples);
+        assert_eq!(
+            weighted(&[("1", 1), ("2", 1), ("3", 5)]),
+            Some(decimal("3"))
+        );
+        assert_eq!(
+            weighted(&[("3", 1), ("1", 3), ("2", 1)]),
+            Some(decimal("1"))
+        );
+        assert_eq!(weighted(&[("1", 2), ("2", 2)]), Some(decimal("1")));
+        assert_eq!(weighted(&[("1", 0), ("2", 0)]), Some(decimal("1")));
+    }
+
+    #[test]
+    fn test_integer_samples() {
+        let mut rates = [7u64, 2, 4, u64::MAX];
+        assert_eq!(Strategy::Median.aggregate_values(&mut rates), Some(5));
+        assert_eq!(rates, [2, 4, 7, u64::MAX]);
+        assert_eq!(Strategy::UpperMedian.aggregate_values(&mut rates), Some(7));
+        assert_eq!(
+            Strategy::TrimmedMean { percent: 0 }.aggregate_values(&mut [u64::MAX, u64::MAX]),
+            Some(u64::MAX)
+        );
+    }
+
+    fn decimals(values: &[&str]) -> Vec<Decimal> {
+        values.iter().map(|v| v.parse().unwrap()).collect()
+    }
+
+    fn outliers(values: &[&str], floor: &str) -> Vec<bool> {
+        mad_outliers(&decimals(values), decimal("3"), decimal(floor))
+    }
+
+    #[test]
+    fn test_mad_outliers() {
+        // median 101, deviations 1, 0, 49 and a MAD of 1
+        assert_eq!(
+            outliers(&["100", "101", "150"], "0"),
+            vec![false, false, true]
+        );
+        assert_eq!(outliers(&["100", "101", "103", "98"], "0"), vec![false; 4]);
+        assert_eq!(outliers(&["100", "1e9"], "0"), vec![false, false]);
+        assert_eq!(outliers(&[], "0"), Vec::<bool>::new());
+        assert_eq!(
+            outliers(&["100", "101", "150", "100.5"], "0"),
+            vec![false, false, true, false]
+        );
+    }
+
+    #[test]
+    fn test_mad_outliers_floor() {
+        // Two values agree exactly, so the MAD is 0
+        assert_eq!(
+            outliers(&["100", "100", "100.1"], "0"),
+            vec![false, false, true]
+        );
+        // A MAD of at least 0.5 keeps values within 1.5 of the median
+        assert_eq!(outliers(&["100", "100", "100.1"], "0.005"), vec![false; 3]);
+        assert_eq!(
+            outliers(&["100", "100", "101.6"], "0.005"),
+            vec![false, false, true]
+        );
+        // The floor only raises the MAD
+        assert_eq!(
+            outliers(&["100", "101", "150"], "0.005"),
+            vec![false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_decimal_samples() {
+        let mut prices = decimals(&["0.3", "0.1", "0.2", "0.4"]);
+        assert_eq!(
+            Strategy::Median.aggregate_values(&mut prices),
+            Some(decimal("0.25"))
+        );
+        // One unit short of a third, where an f64 mean is not exact
+        assert_eq!(
+            Decimal::mean(&decimals(&["0.000000000000000001", "0", "0"])),
+            Decimal::ZERO
+        );
+        assert_eq!(Decimal::mean(&[Decimal::MAX, Decimal::MAX]), Decimal::MAX);
+    }
+
+    #[test]
+    fn test_strategy_names() {
+        for strategy in [
+            Strategy::Median,
+            Strategy::LowerMedian,
+            Strategy::UpperMedian,
+            Strategy::TrimmedMean { percent: 10 },
+            Strategy::WeightedMedian,
+        ] {
+            assert_eq!(strategy.to_string().parse(), Ok(strategy));
+        }
+        assert!("trimmed_mean:50".parse::<Strategy>().is_err());
+        assert!("trimmed_mean:".parse::<Strategy>().is_err());
+        assert!("mean".parse::<Strategy>().is_err());
+    }
+}
@@ -0,0 +1,19 @@
+use crate::coverage::ExchangeSet;
+
+/// Registry of the symbols a price script serves, keyed by the exact ticker
+/// spelling requesters and data sources use, e.g. `1INCH` or `aUST`.
+pub type SymbolMap = phf::Map<&'static str, SymbolInfo>;
+
+/// Other spellings of a price script's symbols, keyed by uppercase spelling
+/// and mapped to the canonical `SymbolMap` key.
+pub type AliasMap = phf::Map<&'static str, &'static str>;
+
+/// What a price script knows about one symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolInfo {
+    /// Exchanges that price the symbol, built with
+    /// [`exchanges!`](crate::exchanges).
+    pub exchanges: ExchangeSet,
+    /// Exchanges the symbol's rate needs prices from, at least 1.
+    pub min_sources: u8,
+}
@@ -0,0 +1,79 @@
+//! Data source selection shared by the VRF scripts.
+
+use sha3::{Digest, Sha3_256};
+
+/// Returns the SHA3-256 digest of `x`.
+pub fn get_hash(x: &[u8]) -> Vec<u8> {
+    Sha3_256::digest(x).to_vec()
+}
+
+/// Picks the data source serving a VRF request: the SHA3-256 digest of `s`,
+/// read as a big-endian 256-bit number, modulo `num_data_source`.
+///
+/// Panics if `num_data_source` is 0.
+pub fn get_random_ds_index_from_seed(s: &str, num_data_source: usize) -> usize {
+    let nds = num_data_source as u128;
+
+    // compute remainders for [256^31,...,256^2,256^1,256^0] mod nds
+    let pre_compute_remainders = (0..32).fold(vec![0u128; 32], |mut s, i| {
+        s[31 - i] = match i {
+            0 => 1,
+            _ => (s[31 + 1 - i] * 256) % nds,
+        };
+        s
+    });
+
+    // selected data source index = random_hash_from_seed % NUM_DS
+    get_hash(s.as_bytes())
+        .iter()
+        .map(|&x| x as u128)
+        .zip(pre_compute_remainders.iter())
+        .fold(0u128, |selected_ds_i, (h, r)| {
+            (((h * r) % nds) + selected_ds_i) % nds
+        }) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigUint;
+    use proptest::prelude::*;
+
+    fn reference(s: &str, num_data_source: usize) -> usize {
+        let digest = BigUint::from_bytes_be(&get_hash(s.as_bytes()));
+        let index = digest % BigUint::from(num_data_source);
+        index.try_into().unwrap()
+    }
+
+    #[test]
+    fn test_get_hash() {
+        assert_eq!(
+            hex::encode(get_hash(b"")),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+    }
+
+    #[test]
+    fn test_single_data_source() {
+        assert_eq!(get_random_ds_index_from_seed("mumu 1234", 1), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_reference_for_every_u8_count(s in ".*") {
+            for n in 1..=u8::MAX as usize {
+                prop_assert_eq!(get_random_ds_index_from_seed(&s, n), reference(&s, n));
+            }
+        }
+
+        #[test]
+        fn test_matches_reference_for_larger_counts(s in ".*", n in 256..=u32::MAX as usize) {
+            prop_assert_eq!(get_random_ds_index_from_seed(&s, n), reference(&s, n));
+        }
+
+        #[test]
+        fn test_matches_reference_for_any_count(s in ".*", n in 1..=usize::MAX) {
+            prop_assert_eq!(get_random_ds_index_from_seed(&s, n), reference(&s, n));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Serves recorded API payloads for the data sources.
+//!
+//! Usage: `cargo run -p os-datasource --bin ds-fixture-server -- [--port <n>]`
+//!
+//! Serves `fixtures/http/`, plus `simple_json.json` and
+//! `mock_opensea_api.json` of the repository root on `/simple_json.json`
+//! and `/mock_opensea_api.json`. Point `DS_BASE_URL` at the printed URL.
+
+use os_datasource::server::repository_fixtures;
+use std::net::TcpListener;
+use std::process::exit;
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let mut port = 8000;
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next().and_then(|v| v.parse().ok())) {
+            ("--port", Some(value)) => port = value,
+            _ => {
+                eprintln!("usage: ds-fixture-server [--port <n>]");
+                exit(2);
+            }
+        }
+    }
+
+    let server = repository_fixtures();
+    let listener = TcpListener::bind(("127.0.0.1", port)).unwrap_or_else(|err| {
+        eprintln!("port {}: {}", port, err);
+        exit(1)
+    });
+    println!("serving on http://127.0.0.1:{}", port);
+    if let Err(err) = server.serve(listener) {
+        eprintln!("{}", err);
+        exit(1);
+    }
+}
@@ -0,0 +1,24 @@
+//! JSON data source: prints a JSON document, or the item or pointer of it
+//! the calldata selects.
+//!
+//! Usage: `ds-json <path> [<index> | <pointer>]`, or
+//! `ds-json [<index> | <pointer>]` with `DS_PATH` naming the document, e.g.
+//! `DS_PATH=/simple_json.json ds-json 1` for get_and_return_simple_json.
+
+use os_datasource::{base_url, report, source};
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if let Ok(path) = std::env::var("DS_PATH") {
+        args.insert(0, path);
+    }
+    if args.is_empty() || args.len() > 2 {
+        eprintln!("usage: ds-json <path> [<index> | <pointer>]");
+        std::process::exit(2);
+    }
+    report(
+        base_url().and_then(|base_url| {
+            source::json(&base_url, &args[0], args.get(1).map(String::as_str))
+        }),
+    );
+}
@@ -0,0 +1,21 @@
+//! Price data source: prints the prices of the symbols on an exchange,
+//! comma-separated in the order asked.
+//!
+//! Usage: `ds-price <exchange> <symbol>...`, the calldata of a CCXT request,
+//! or `ds-price <symbol>...` with `DS_EXCHANGE` naming the exchange of an API
+//! data source.
+
+use os_datasource::{base_url, report, source};
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let exchange = match std::env::var("DS_EXCHANGE") {
+        Ok(exchange) => exchange,
+        Err(_) if !args.is_empty() => args.remove(0),
+        Err(_) => {
+            eprintln!("usage: ds-price <exchange> <symbol>...");
+            std::process::exit(2);
+        }
+    };
+    report(base_url().and_then(|base_url| source::price(&base_url, &exchange, &args)));
+}
@@ -0,0 +1,15 @@
+//! VRF data source: prints the proof and output of a VRF provider for a
+//! seed and time, hex-encoded back to back.
+//!
+//! Usage: `ds-vrf <seed> <time>`, the calldata of the VRF scripts.
+
+use os_datasource::{base_url, report, source};
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() != 2 {
+        eprintln!("usage: ds-vrf <seed> <time>");
+        std::process::exit(2);
+    }
+    report(base_url().and_then(|base_url| source::vrf(&base_url, &args[0], &args[1])));
+}
@@ -0,0 +1,43 @@
+//! Data sources the oracle scripts call, and a server replaying recorded
+//! payloads so they run offline.
+//!
+//! A data source is an executable a validator runs with the calldata of a
+//! raw request as arguments; what it prints is the validator's report, and
+//! a non-zero exit status fails the report. The executables here fetch from
+//! the API at `DS_BASE_URL`:
+//!
+//! - `ds-price [<exchange>] <symbol>...` prints comma-separated prices, `-`
+//!   for a symbol without one, see [`source::price`];
+//! - `ds-vrf <seed> <time>` prints a 288-character VRF report, see
+//!   [`source::vrf`];
+//! - `ds-json <path> [<index> | <pointer>]` prints a value of a JSON
+//!   document, see [`source::json`].
+//!
+//! A data source deployed for one exchange or document takes it from
+//! `DS_EXCHANGE` or `DS_PATH` instead of the first argument.
+//!
+//! `ds-fixture-server` serves `fixtures/http/` and the sample payloads of the
+//! repository root with [`server::FixtureServer`]; pointing `DS_BASE_URL` at
+//! it runs every data source without network access.
+
+pub mod server;
+pub mod source;
+
+/// Environment variable holding the base URL data sources fetch from.
+pub const BASE_URL_VAR: &str = "DS_BASE_URL";
+
+/// Returns the base URL data sources fetch from.
+pub fn base_url() -> Result<String, String> {
+    std::env::var(BASE_URL_VAR).map_err(|_| format!("{} is not set", BASE_URL_VAR))
+}
+
+/// Prints the report of a data source, or fails it with the error.
+pub fn report(result: Result<String, String>) {
+    match result {
+        Ok(report) => println!("{}", report),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
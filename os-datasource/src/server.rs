@@ -0,0 +1,157 @@
+//! HTTP server answering `GET` requests with recorded payloads.
+//!
+//! Requests are matched on their path, the query string is ignored. A path
+//! is served from the files mounted on it, then from the root directory.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::{Component, Path, PathBuf};
+use std::thread;
+
+/// The recorded payloads of this repository: `fixtures/http/`, plus
+/// `simple_json.json` and `mock_opensea_api.json` of the root on
+/// `/simple_json.json` and `/mock_opensea_api.json`.
+pub fn repository_fixtures() -> FixtureServer {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    FixtureServer::new(root.join("fixtures/http"))
+        .file("/simple_json.json", root.join("simple_json.json"))
+        .file("/mock_opensea_api.json", root.join("mock_opensea_api.json"))
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FixtureServer {
+    root: Option<PathBuf>,
+    files: BTreeMap<String, PathBuf>,
+}
+
+impl FixtureServer {
+    /// Serves the files under `root`, e.g. `root/prices/binance.json` on
+    /// `/prices/binance.json`.
+    pub fn new(root: impl Into<PathBuf>) -> FixtureServer {
+        FixtureServer {
+            root: Some(root.into()),
+            files: BTreeMap::new(),
+        }
+    }
+
+    /// Serves `file` on `path`, which starts with `/`.
+    pub fn file(mut self, path: &str, file: impl Into<PathBuf>) -> FixtureServer {
+        self.files.insert(path.to_string(), file.into());
+        self
+    }
+
+    /// Answers requests on `listener` until it fails.
+    pub fn serve(&self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let stream = stream?;
+            let server = self.clone();
+            thread::spawn(move || {
+                let _ = server.answer(stream);
+            });
+        }
+        Ok(())
+    }
+
+    /// Serves on a free local port from a background thread and returns
+    /// its address.
+    pub fn start(self) -> io::Result<SocketAddr> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        thread::spawn(move || self.serve(listener));
+        Ok(addr)
+    }
+
+    /// Returns the file served on `path`, if any.
+    pub fn resolve(&self, path: &str) -> Option<PathBuf> {
+        if let Some(file) = self.files.get(path) {
+            return Some(file.clone());
+        }
+        let relative = Path::new(path.strip_prefix('/')?);
+        // Never serve outside the root
+        if !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return None;
+        }
+        let file = self.root.as_ref()?.join(relative);
+        file.is_file().then_some(file)
+    }
+
+    fn answer(&self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // Skip the headers, requests have no body
+        let mut header = String::new();
+        while reader.read_line(&mut header)? > 2 {
+            header.clear();
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let (method, target) = (parts.next(), parts.next().unwrap_or_default());
+        let path = target.split('?').next().unwrap_or_default();
+        let (status, body) = match method {
+            Some("GET") => match self.resolve(path).map(fs::read) {
+                Some(Ok(body)) => ("200 OK", body),
+                _ => (
+                    "404 Not Found",
+                    format!("no fixture for {}\n", path).into_bytes(),
+                ),
+            },
+            _ => ("405 Method Not Allowed", Vec::new()),
+        };
+        let content_type = match path.ends_with(".json") {
+            true => "application/json",
+            false => "text/plain",
+        };
+
+        let mut stream = stream;
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            status,
+            content_type,
+            body.len()
+        )?;
+        stream.write_all(&body)?;
+        stream.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn get(addr: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_serve() {
+        let addr = repository_fixtures().start().unwrap();
+
+        let response = get(
+            addr,
+            "GET /simple_json.json?x=1 HTTP/1.1\r\nHost: a\r\n\r\n",
+        );
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("terra1wu25uuumx0nc6fk4fcaqk8mcx4dhnxwca56rc5"));
+
+        let response = get(addr, "GET /prices/binance.json HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+
+        let response = get(addr, "GET /../Cargo.toml HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+        let response = get(addr, "POST /simple_json.json HTTP/1.1\r\n\r\n");
+        assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    }
+}
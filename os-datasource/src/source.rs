@@ -0,0 +1,145 @@
+//! What the data source executables fetch and how they turn it into a
+//! report. Each function returns the report or why the data source fails.
+
+use os_common::report::{parse_prices, parse_vrf_report, MISSING_PRICE};
+use serde_json::Value;
+
+/// Fetches the JSON document at `path` of the API at `base_url`.
+pub fn fetch_json(base_url: &str, path: &str) -> Result<Value, String> {
+    let url = format!("{}{}", base_url.trim_end_matches('/'), path);
+    let body = ureq::get(&url)
+        .call()
+        .map_err(|err| format!("{}: {}", url, err))?
+        .into_string()
+        .map_err(|err| format!("{}: {}", url, err))?;
+    serde_json::from_str(&body).map_err(|err| format!("{}: {}", url, err))
+}
+
+/// Prices of `symbols` on `exchange`, comma-separated in the order asked.
+///
+/// `/prices/<exchange>.json` maps symbols to prices. A symbol without a
+/// price is reported as [`MISSING_PRICE`], keeping the others in place.
+pub fn price(base_url: &str, exchange: &str, symbols: &[String]) -> Result<String, String> {
+    if symbols.is_empty() {
+        return Err("no symbols".into());
+    }
+    let path = format!("/prices/{}.json", exchange.to_ascii_lowercase());
+    let prices = fetch_json(base_url, &path)?;
+    let report = symbols
+        .iter()
+        .map(|symbol| match prices.get(symbol).and_then(Value::as_f64) {
+            Some(px) => px.to_string(),
+            None => MISSING_PRICE.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    match parse_prices(&report, symbols.len()) {
+        Some(_) => Ok(report),
+        None => Err(format!("{} sent an invalid price: {}", exchange, report)),
+    }
+}
+
+/// VRF proof and output for `seed` at `time`, hex-encoded back to back.
+///
+/// `/vrf/<seed>/<time>.json` holds `{"proof": <hex>, "result": <hex>}`.
+pub fn vrf(base_url: &str, seed: &str, time: &str) -> Result<String, String> {
+    let response = fetch_json(base_url, &format!("/vrf/{}/{}.json", seed, time))?;
+    let field = |name: &str| {
+        response
+            .get(name)
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("VRF response has no {}", name))
+    };
+    let report = format!("{}{}", field("proof")?, field("result")?);
+    match parse_vrf_report(&report) {
+        Some(_) => Ok(report),
+        None => Err(format!("malformed VRF report: {}", report)),
+    }
+}
+
+/// The JSON document at `path`, or the part `selector` picks: an array
+/// index, negative from the end, or a JSON pointer such as `/assets/0/id`.
+/// Strings are reported without quotes, anything else as compact JSON.
+pub fn json(base_url: &str, path: &str, selector: Option<&str>) -> Result<String, String> {
+    let document = fetch_json(base_url, path)?;
+    let value = match selector {
+        None => &document,
+        Some(pointer) if pointer.starts_with('/') => document
+            .pointer(pointer)
+            .ok_or_else(|| format!("{} has nothing at {}", path, pointer))?,
+        Some(index) => {
+            let items = document
+                .as_array()
+                .ok_or_else(|| format!("{} is not an array", path))?;
+            let index: i64 = index
+                .parse()
+                .map_err(|_| format!("bad index or pointer {:?}", index))?;
+            let position = match index < 0 {
+                true => items.len() as i64 + index,
+                false => index,
+            };
+            usize::try_from(position)
+                .ok()
+                .and_then(|position| items.get(position))
+                .ok_or_else(|| format!("{} has no item {}", path, index))?
+        }
+    };
+    Ok(match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::repository_fixtures;
+
+    fn base_url() -> String {
+        format!("http://{}", repository_fixtures().start().unwrap())
+    }
+
+    fn symbols(symbols: &[&str]) -> Vec<String> {
+        symbols.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_price() {
+        let base_url = base_url();
+        assert_eq!(
+            price(&base_url, "BINANCE", &symbols(&["ETH", "BTC"])),
+            Ok("1800.5,30000".into())
+        );
+        assert_eq!(
+            price(&base_url, "binance", &symbols(&["DOGECOINX", "BTC"])),
+            Ok("-,30000".into())
+        );
+        assert!(price(&base_url, "nowhere", &symbols(&["BTC"])).is_err());
+    }
+
+    #[test]
+    fn test_vrf() {
+        let report = vrf(&base_url(), "6d756d75", "1234").unwrap();
+        assert_eq!(report.len(), 288);
+        assert!(vrf(&base_url(), "6d756d75", "1").is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        let base_url = base_url();
+        assert_eq!(
+            json(&base_url, "/simple_json.json", Some("-1")),
+            Ok(r#"{"address":"terra1l9drxzsmxrlspm73wurxnptsawyrn3s63k7qd4","score":84}"#.into())
+        );
+        assert_eq!(
+            json(&base_url, "/simple_json.json", Some("/0/address")),
+            Ok("terra1wu25uuumx0nc6fk4fcaqk8mcx4dhnxwca56rc5".into())
+        );
+        assert_eq!(
+            json(&base_url, "/mock_opensea_api.json", Some("/assets/0/id")),
+            Ok("412319733".into())
+        );
+        assert!(json(&base_url, "/simple_json.json", Some("3")).is_err());
+        assert!(json(&base_url, "/simple_json.json", Some("-4")).is_err());
+    }
+}
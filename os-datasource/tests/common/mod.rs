@@ -0,0 +1,25 @@
+use os_common::host::mock::Report;
+use os_datasource::server::repository_fixtures;
+use os_datasource::BASE_URL_VAR;
+use std::process::Command;
+
+/// Starts a server replaying the recorded payloads and returns its URL.
+pub fn base_url() -> String {
+    format!("http://{}", repository_fixtures().start().unwrap())
+}
+
+/// Runs a data source executable with the calldata of a raw request, as a
+/// validator does, and returns the validator's report.
+pub fn run(executable: &str, base_url: &str, calldata: &[u8], envs: &[(&str, &str)]) -> Report {
+    let calldata = String::from_utf8(calldata.to_vec()).unwrap();
+    let output = Command::new(executable)
+        .args(calldata.split_whitespace())
+        .env(BASE_URL_VAR, base_url)
+        .envs(envs.iter().copied())
+        .output()
+        .unwrap();
+    match output.status.code() {
+        Some(0) => Report::Data(String::from_utf8(output.stdout).unwrap().trim_end().into()),
+        code => Report::Failed(code.unwrap_or(-1) as i64),
+    }
+}
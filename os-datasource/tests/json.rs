@@ -0,0 +1,49 @@
+//! get_and_return_simple_json end to end: prepare, `ds-json` against
+//! simple_json.json, execute.
+
+mod common;
+
+use obi::{OBIDecode, OBIEncode};
+use os_common::host::mock::Host;
+
+#[derive(OBIEncode)]
+struct Input {
+    sliced_index_input: i8,
+}
+
+#[derive(OBIDecode)]
+struct Output {
+    result: String,
+}
+
+#[test]
+fn test_get_and_return_simple_json() {
+    let base_url = common::base_url();
+    let calldata = Input {
+        sliced_index_input: 1,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let (asked, result) = Host::new(calldata.clone()).run(get_and_return_simple_json::prepare);
+    assert_eq!(result, Ok(()));
+    let request = &asked.asked[0];
+    let report = common::run(
+        env!("CARGO_BIN_EXE_ds-json"),
+        &base_url,
+        &request.calldata,
+        &[("DS_PATH", "/simple_json.json")],
+    );
+
+    let (host, result) = Host::new(calldata)
+        .ask_count(2)
+        .report(request.eid, 0, report.clone())
+        .report(request.eid, 1, report)
+        .run(get_and_return_simple_json::execute);
+    assert_eq!(result, Ok(()));
+    let output = Output::try_from_slice(&host.return_data.unwrap()).unwrap();
+    assert_eq!(
+        output.result,
+        r#"{"address":"terra1fnvgss8vv8z84umlav0ey0smh97grkegxl9p5r","score":80}"#
+    );
+}
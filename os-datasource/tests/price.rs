@@ -0,0 +1,68 @@
+//! linear_os end to end: prepare, `ds-price` against the recorded prices,
+//! execute.
+
+mod common;
+
+use obi::{OBIDecode, OBIEncode};
+use os_build::coverage::COVERAGE_FILE;
+use os_build::Coverage;
+use os_common::host::mock::{Host, Report};
+use os_common::RejectedExchanges;
+use std::path::Path;
+
+#[derive(OBIEncode)]
+struct Input {
+    symbols: Vec<String>,
+    multiplier: u64,
+}
+
+#[derive(OBIDecode)]
+struct Output {
+    rates: Vec<u64>,
+    rejected_exchanges: Vec<RejectedExchanges>,
+}
+
+#[test]
+fn test_linear_os() {
+    let base_url = common::base_url();
+    let coverage_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../scripts/linear_os")
+        .join(COVERAGE_FILE);
+    let coverage = Coverage::load(&coverage_path).unwrap();
+    let calldata = Input {
+        symbols: vec!["ETH".into(), "BTC".into()],
+        multiplier: 100,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let (asked, result) = Host::new(calldata.clone()).run(linear_os::prepare);
+    assert_eq!(result, Ok(()));
+
+    let mut host = Host::new(calldata).ask_count(3).min_count(2);
+    let mut reported = 0;
+    for request in asked.asked {
+        // linear_os asks every exchange through its own data source
+        let exchange = &coverage.exchanges[request.eid as usize].name;
+        let report = common::run(
+            env!("CARGO_BIN_EXE_ds-price"),
+            &base_url,
+            &request.calldata,
+            &[("DS_EXCHANGE", exchange)],
+        );
+        if matches!(report, Report::Data(_)) {
+            reported += 1;
+        }
+        for vid in 0..3 {
+            host = host.report(request.eid, vid, report.clone());
+        }
+    }
+    // binance, coingecko and kraken have recorded prices
+    assert_eq!(reported, 3);
+
+    let (host, result) = host.run(linear_os::execute);
+    assert_eq!(result, Ok(()));
+    let output = Output::try_from_slice(&host.return_data.unwrap()).unwrap();
+    assert_eq!(output.rates, vec![180050, 3000000]);
+    assert_eq!(output.rejected_exchanges, vec![]);
+}
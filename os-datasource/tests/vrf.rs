@@ -0,0 +1,51 @@
+//! vrf_rsds end to end: prepare, `ds-vrf` against the recorded VRF
+//! response, execute.
+
+mod common;
+
+use obi::{OBIDecode, OBIEncode};
+use os_common::host::mock::Host;
+
+#[derive(OBIEncode)]
+struct Input {
+    seed: Vec<u8>,
+    time: u64,
+    worker_address: Vec<u8>,
+}
+
+#[derive(OBIDecode)]
+struct Output {
+    proof: Vec<u8>,
+    result: Vec<u8>,
+}
+
+#[test]
+fn test_vrf_rsds() {
+    let base_url = common::base_url();
+    let calldata = Input {
+        seed: b"mumu".to_vec(),
+        time: 1234,
+        worker_address: vec![],
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let (asked, result) = Host::new(calldata.clone()).run(vrf_rsds::prepare);
+    assert_eq!(result, Ok(()));
+    let request = &asked.asked[0];
+    assert_eq!(request.calldata, b"6d756d75 1234");
+    let report = common::run(
+        env!("CARGO_BIN_EXE_ds-vrf"),
+        &base_url,
+        &request.calldata,
+        &[],
+    );
+
+    let (host, result) = Host::new(calldata)
+        .report(request.eid, 0, report)
+        .run(vrf_rsds::execute);
+    assert_eq!(result, Ok(()));
+    let output = Output::try_from_slice(&host.return_data.unwrap()).unwrap();
+    assert_eq!(output.proof, vec![0xab; 80]);
+    assert_eq!(output.result, vec![0xcd; 64]);
+}
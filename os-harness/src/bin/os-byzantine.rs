@@ -0,0 +1,130 @@
+//! Estimates, for every token of a price script, the worst price deviation
+//! `k` colluding validators and `m` compromised sources can cause, using the
+//! token's coverage in the script's `coverage.toml`.
+//!
+//! Usage: `cargo run -p os-harness --bin os-byzantine -- <script dir> [options]`
+//!
+//! Options:
+//!   --validators <n>          validators asked per request (16)
+//!   -k <n>                    colluding validators (0)
+//!   -m <n>                    compromised sources per token (1)
+//!   --spread <f>              honest quotes within this fraction of the price (0.005)
+//!   --trials <n>              trials per token (1000)
+//!   --seed <n>                seed of the trials (1)
+//!   --threshold <f>           flag tokens deviating more than this fraction (0.01)
+//!   --validator-strategy <s>  combines the validator reports of a source (median)
+//!   --source-strategy <s>     combines the prices of the sources (median)
+//!   --outlier-cutoff <f>      rejects sources this many median absolute
+//!                             deviations off (3, as linear_os)
+//!   --outlier-floor <f>       least median absolute deviation, as a fraction
+//!                             of the price (0.005)
+//!   --keep-outliers           rejects no source
+//!   --upper-median            same as --validator-strategy upper_median, as hkt_price
+//!
+//! Strategies are spelled as `os_common::Strategy` parses them, e.g.
+//! `lower_median` or `trimmed_mean:10`.
+//!
+//! Tokens are listed from the most to the least exposed.
+
+use os_build::coverage::COVERAGE_FILE;
+use os_build::Coverage;
+use os_common::{Aggregation, Strategy, DEFAULT_OUTLIER_CUTOFF};
+use os_harness::byzantine::{worst_deviation, Rng, Scenario};
+use std::path::Path;
+use std::process::exit;
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: os-byzantine <script dir> [--validators <n>] [-k <n>] [-m <n>] [--spread <f>] \
+         [--trials <n>] [--seed <n>] [--threshold <f>] [--validator-strategy <s>] \
+         [--source-strategy <s>] [--outlier-cutoff <f> | --keep-outliers] [--outlier-floor <f>] \
+         [--upper-median]"
+    );
+    exit(2)
+}
+
+fn parse<T: std::str::FromStr>(value: Option<String>) -> T {
+    value
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_else(|| usage())
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let script_dir = args.next().unwrap_or_else(|| usage());
+    let mut scenario = Scenario {
+        validators: 16,
+        adversarial_validators: 0,
+        compromised_sources: 1,
+        spread: 0.005,
+        trials: 1000,
+        aggregation: Aggregation::MEDIAN.reject_outliers(DEFAULT_OUTLIER_CUTOFF),
+    };
+    let mut seed = 1;
+    let mut threshold = 0.01;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--validators" => scenario.validators = parse(args.next()),
+            "-k" => scenario.adversarial_validators = parse(args.next()),
+            "-m" => scenario.compromised_sources = parse(args.next()),
+            "--spread" => scenario.spread = parse(args.next()),
+            "--trials" => scenario.trials = parse(args.next()),
+            "--seed" => seed = parse(args.next()),
+            "--threshold" => threshold = parse(args.next()),
+            "--validator-strategy" => scenario.aggregation.validators = parse(args.next()),
+            "--source-strategy" => scenario.aggregation.exchanges = parse(args.next()),
+            "--outlier-cutoff" => scenario.aggregation.outlier_cutoff = Some(parse(args.next())),
+            "--keep-outliers" => scenario.aggregation.outlier_cutoff = None,
+            "--outlier-floor" => scenario.aggregation.outlier_floor = parse(args.next()),
+            "--upper-median" => scenario.aggregation.validators = Strategy::UpperMedian,
+            _ => usage(),
+        }
+    }
+
+    let coverage_path = Path::new(&script_dir).join(COVERAGE_FILE);
+    let rows = Coverage::load(&coverage_path)
+        .and_then(|coverage| {
+            coverage.token_rows().map(|rows| {
+                rows.into_iter()
+                    .map(|(symbol, bits)| (symbol.to_string(), bits.matches('1').count()))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .unwrap_or_else(|err| {
+            eprintln!("{}: {}", coverage_path.display(), err);
+            exit(1)
+        });
+
+    let mut rng = Rng::new(seed);
+    let mut results: Vec<(String, usize, f64)> = rows
+        .into_iter()
+        .map(|(symbol, sources)| {
+            let deviation = worst_deviation(sources, &scenario, &mut rng);
+            (symbol, sources, deviation)
+        })
+        .collect();
+    results.sort_by(|a, b| b.2.total_cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+
+    println!(
+        "{} validators, k = {} colluding, m = {} compromised per token, {} trials",
+        scenario.validators,
+        scenario.adversarial_validators,
+        scenario.compromised_sources,
+        scenario.trials
+    );
+    println!("{:<12} {:>7}  worst deviation", "token", "sources");
+    for (symbol, sources, deviation) in results {
+        let flag = if deviation > threshold { "  !" } else { "" };
+        if deviation >= 1.0 {
+            println!("{:<12} {:>7}  unbounded{}", symbol, sources, flag);
+        } else {
+            println!(
+                "{:<12} {:>7}  {:.3}%{}",
+                symbol,
+                sources,
+                deviation * 100.0,
+                flag
+            );
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Runs the prepare phase of a compiled oracle script and prints the raw
+//! requests it asks for: external ID, data source ID, the exchange or VRF
+//! provider behind it, and the calldata the data source gets.
+//!
+//! Usage: `cargo run -p os-harness --bin os-plan -- <script.wasm> [options]`,
+//! with the script built as described in the `os_harness` crate docs.
+//!
+//! Options:
+//!   --calldata <hex>          calldata of the request
+//!   --symbols <A,B,...>       price script calldata with these symbols
+//!   --multiplier <n>          multiplier of the price calldata (1000000000)
+//!   --seed <hex> --time <n>   VRF script calldata with this seed and time
+//!   --worker <hex>            worker address, for VRF inputs that have one
+//!   --script <dir>            script directory (scripts/<name of the wasm>)
+//!   --network <name>          network profile the script was built against
+//!                             ($BAND_NETWORK, otherwise the
+//!                             default_network of the script's Cargo.toml)
+//!   --ask-count <n>           validators asked (16)
+//!   --min-count <n>           validators required (10)
+//!
+//! Exits with 1 if prepare fails and 2 on usage error.
+
+use obi::OBIEncode;
+use os_harness::plan::Sources;
+use os_harness::{describe_failure, price_calldata, Host, Phase, Runtime};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: os-plan <script.wasm> (--calldata <hex> | --symbols <A,B,...> [--multiplier <n>] \
+         | --seed <hex> --time <n> [--worker <hex>]) [--script <dir>] [--network <name>] \
+         [--ask-count <n>] [--min-count <n>]"
+    );
+    exit(2)
+}
+
+fn parse<T: std::str::FromStr>(value: Option<String>) -> T {
+    value
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_else(|| usage())
+}
+
+fn parse_hex(value: Option<String>) -> Vec<u8> {
+    hex::decode(parse::<String>(value)).unwrap_or_else(|_| usage())
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let wasm_path = args.next().unwrap_or_else(|| usage());
+    let mut calldata = None;
+    let mut symbols: Option<Vec<String>> = None;
+    let mut multiplier = 1_000_000_000;
+    let mut seed = None;
+    let mut time: Option<u64> = None;
+    let mut worker = None;
+    let mut script_dir = None;
+    let mut network = std::env::var(os_build::profile::NETWORK_ENV).ok();
+    let mut ask_count = 16;
+    let mut min_count = 10;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--calldata" => calldata = Some(parse_hex(args.next())),
+            "--symbols" => {
+                let list: String = parse(args.next());
+                symbols = Some(list.split(',').map(|s| s.to_string()).collect())
+            }
+            "--multiplier" => multiplier = parse(args.next()),
+            "--seed" => seed = Some(parse_hex(args.next())),
+            "--time" => time = Some(parse(args.next())),
+            "--worker" => worker = Some(parse_hex(args.next())),
+            "--script" => script_dir = Some(PathBuf::from(parse::<String>(args.next()))),
+            "--network" => network = Some(parse(args.next())),
+            "--ask-count" => ask_count = parse(args.next()),
+            "--min-count" => min_count = parse(args.next()),
+            _ => usage(),
+        }
+    }
+    let calldata = match (calldata, symbols, seed, time) {
+        (Some(calldata), None, None, None) => calldata,
+        (None, Some(symbols), None, None) => price_calldata(&symbols, multiplier),
+        (None, None, Some(seed), Some(time)) => {
+            let mut calldata = seed.try_to_vec().unwrap();
+            calldata.extend(time.try_to_vec().unwrap());
+            if let Some(worker) = worker {
+                calldata.extend(worker.try_to_vec().unwrap());
+            }
+            calldata
+        }
+        _ => usage(),
+    };
+    let script_dir = script_dir.unwrap_or_else(|| {
+        let name = Path::new(&wasm_path)
+            .file_stem()
+            .unwrap_or_else(|| usage())
+            .to_string_lossy()
+            .into_owned();
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../scripts")
+            .join(name)
+    });
+    let sources = Sources::load(&script_dir, network.as_deref()).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        exit(1)
+    });
+
+    let wasm = std::fs::read(&wasm_path).unwrap_or_else(|err| {
+        eprintln!("{}: {}", wasm_path, err);
+        exit(1)
+    });
+    let mut runtime = Runtime::new(&wasm).unwrap_or_else(|err| {
+        eprintln!("{}: does not compile: {}", wasm_path, err);
+        exit(1)
+    });
+    let host = Host::new(calldata)
+        .ask_count(ask_count)
+        .min_count(min_count);
+    let (host, gas_used) = runtime.run(Phase::Prepare, host, u64::MAX);
+    if let Err(err) = gas_used {
+        eprintln!("prepare failed: {}", describe_failure(&host, err));
+        exit(1)
+    }
+    for line in sources.render(&host.asked) {
+        println!("{}", line);
+    }
+}
@@ -0,0 +1,114 @@
+//! Runs a compiled oracle script through prepare and execute in a local
+//! owasm runtime, and prints the gas each phase used.
+//!
+//! Usage: `cargo run -p os-harness --bin os-run -- <script.wasm> [options]`,
+//! with the script built as described in the `os_harness` crate docs.
+//!
+//! Options:
+//!   --calldata <hex>          calldata of the request
+//!   --symbols <A,B,...>       price script calldata with these symbols
+//!   --multiplier <n>          multiplier of the price calldata (1000000000)
+//!   --ask-count <n>           validators asked (16)
+//!   --min-count <n>           validators required (10)
+//!   --price <p>               price every price script raw request reports
+//!                             (1 with --symbols, otherwise no reports)
+//!   --gas-limit <n>           gas limit of each phase (unlimited)
+//!   --span-size <n>           span size of the runtime (1024)
+//!
+//! Exits with 1 if a phase fails, e.g. runs out of gas, and 2 on usage error.
+
+use os_harness::{describe_failure, price_calldata, price_reports, Host, Phase, Runtime};
+use std::process::exit;
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: os-run <script.wasm> (--calldata <hex> | --symbols <A,B,...> [--multiplier <n>]) \
+         [--ask-count <n>] [--min-count <n>] [--price <p>] [--gas-limit <n>] [--span-size <n>]"
+    );
+    exit(2)
+}
+
+fn parse<T: std::str::FromStr>(value: Option<String>) -> T {
+    value
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_else(|| usage())
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let wasm_path = args.next().unwrap_or_else(|| usage());
+    let mut calldata = None;
+    let mut symbols: Option<Vec<String>> = None;
+    let mut multiplier = 1_000_000_000;
+    let mut ask_count = 16;
+    let mut min_count = 10;
+    let mut price: Option<String> = None;
+    let mut gas_limit = u64::MAX;
+    let mut span_size = os_harness::DEFAULT_SPAN_SIZE;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--calldata" => {
+                calldata =
+                    Some(hex::decode(parse::<String>(args.next())).unwrap_or_else(|_| usage()))
+            }
+            "--symbols" => {
+                let list: String = parse(args.next());
+                symbols = Some(list.split(',').map(|s| s.to_string()).collect())
+            }
+            "--multiplier" => multiplier = parse(args.next()),
+            "--ask-count" => ask_count = parse(args.next()),
+            "--min-count" => min_count = parse(args.next()),
+            "--price" => price = Some(parse(args.next())),
+            "--gas-limit" => gas_limit = parse(args.next()),
+            "--span-size" => span_size = parse(args.next()),
+            _ => usage(),
+        }
+    }
+    let calldata = match (calldata, symbols) {
+        (Some(calldata), None) => calldata,
+        (None, Some(symbols)) => {
+            price.get_or_insert_with(|| "1".to_string());
+            price_calldata(&symbols, multiplier)
+        }
+        _ => usage(),
+    };
+
+    let wasm = std::fs::read(&wasm_path).unwrap_or_else(|err| {
+        eprintln!("{}: {}", wasm_path, err);
+        exit(1)
+    });
+    let mut runtime = Runtime::new(&wasm).unwrap_or_else(|err| {
+        eprintln!("{}: does not compile: {}", wasm_path, err);
+        exit(1)
+    });
+    runtime.span_size = span_size;
+
+    let host = Host::new(calldata)
+        .ask_count(ask_count)
+        .min_count(min_count);
+    let (host, gas_used) = runtime.run(Phase::Prepare, host, gas_limit);
+    match gas_used {
+        Ok(gas) => println!("prepare: {} gas, {} raw requests", gas, host.asked.len()),
+        Err(err) => {
+            eprintln!("prepare failed: {}", describe_failure(&host, err));
+            exit(1)
+        }
+    }
+
+    let host = match price {
+        Some(price) => price_reports(host, &price),
+        None => host,
+    };
+    let (host, gas_used) = runtime.run(Phase::Execute, host, gas_limit);
+    match gas_used {
+        Ok(gas) => println!(
+            "execute: {} gas, return data {}",
+            gas,
+            hex::encode(host.return_data.unwrap_or_default())
+        ),
+        Err(err) => {
+            eprintln!("execute failed: {}", describe_failure(&host, err));
+            exit(1)
+        }
+    }
+}
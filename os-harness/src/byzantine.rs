@@ -0,0 +1,207 @@
+//! Monte-Carlo simulation of Byzantine validators and exchanges against the
+//! median pipeline of the price scripts.
+//!
+//! Each trial quotes the true price (1.0) on every source of a token with
+//! some honest noise, then lets the adversary in: `k` validators report the
+//! attack price for every source, and `m` compromised sources hand the
+//! attack price to every validator. Reports go through the same two stages
+//! as [`os_common::aggregate_rates`], with the [`Aggregation`] of the
+//! scenario and on [`Decimal`] prices like it: over validators per source,
+//! rejecting outlying sources, then over sources. The adversary tries a high
+//! and a low attack price; the worst relative deviation over all trials is
+//! what it can cause.
+
+use os_common::stats::mad_outliers;
+use os_common::{Aggregation, Decimal};
+
+const TRUE_PRICE: Decimal = Decimal::from_integer(1);
+
+/// What the adversary reports, as a multiple of the true price.
+const ATTACK_PRICES: [Decimal; 2] = [Decimal::ZERO, Decimal::from_integer(1_000_000_000)];
+
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    /// Validators asked per request.
+    pub validators: usize,
+    /// Colluding validators, `k`.
+    pub adversarial_validators: usize,
+    /// Compromised sources per token, `m`, capped at the token's sources.
+    pub compromised_sources: usize,
+    /// Honest quotes fall within this fraction of the true price.
+    pub spread: f64,
+    pub trials: usize,
+    pub aggregation: Aggregation,
+}
+
+/// SplitMix64, enough for sampling and reproducible from a seed.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// `count` distinct indexes below `n`.
+    fn sample(&mut self, n: usize, count: usize) -> Vec<usize> {
+        let mut indexes: Vec<usize> = (0..n).collect();
+        for i in 0..count.min(n) {
+            let j = i + (self.next_u64() % (n - i) as u64) as usize;
+            indexes.swap(i, j);
+        }
+        indexes.truncate(count.min(n));
+        indexes
+    }
+}
+
+/// An honest quote, within `spread` of the true price.
+fn honest_quote(spread: f64, rng: &mut Rng) -> Decimal {
+    let quote = 1.0 + spread * (2.0 * rng.next_f64() - 1.0);
+    Decimal::from_units((TRUE_PRICE.units() as f64 * quote) as u128)
+}
+
+/// The price the pipeline outputs when `compromised` sources and the first
+/// `adversarial_validators` validators report `attack`.
+fn attacked_price(
+    quotes: &[Decimal],
+    compromised: &[usize],
+    attack: Decimal,
+    scenario: &Scenario,
+) -> Decimal {
+    let mut source_prices: Vec<(Decimal, u64)> = quotes
+        .iter()
+        .enumerate()
+        .map(|(source, &quote)| {
+            let mut reports: Vec<(Decimal, u64)> = (0..scenario.validators)
+                .map(|validator| {
+                    if validator < scenario.adversarial_validators || compromised.contains(&source)
+                    {
+                        (attack, 1)
+                    } else {
+                        (quote, 1)
+                    }
+                })
+                .collect();
+            let price = scenario.aggregation.validators.aggregate(&mut reports);
+            (price.unwrap(), scenario.validators as u64)
+        })
+        .collect();
+    if let Some(cutoff) = scenario.aggregation.outlier_cutoff {
+        let prices: Vec<Decimal> = source_prices.iter().map(|&(price, _)| price).collect();
+        let mut outliers =
+            mad_outliers(&prices, cutoff, scenario.aggregation.outlier_floor).into_iter();
+        source_prices.retain(|_| !outliers.next().unwrap());
+    }
+    scenario
+        .aggregation
+        .exchanges
+        .aggregate(&mut source_prices)
+        .unwrap()
+}
+
+/// Worst relative deviation from the true price the adversary of
+/// `scenario` causes on a token priced by `sources` sources.
+pub fn worst_deviation(sources: usize, scenario: &Scenario, rng: &mut Rng) -> f64 {
+    let mut worst = 0f64;
+    for _ in 0..scenario.trials {
+        let quotes: Vec<Decimal> = (0..sources)
+            .map(|_| honest_quote(scenario.spread, rng))
+            .collect();
+        let compromised = rng.sample(sources, scenario.compromised_sources);
+        for attack in ATTACK_PRICES {
+            let price = attacked_price(&quotes, &compromised, attack, scenario);
+            worst = worst.max(price.abs_diff(TRUE_PRICE).to_f64());
+        }
+    }
+    worst
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_common::{Strategy, DEFAULT_OUTLIER_CUTOFF};
+
+    fn scenario(k: usize, m: usize) -> Scenario {
+        Scenario {
+            validators: 16,
+            adversarial_validators: k,
+            compromised_sources: m,
+            spread: 0.01,
+            trials: 200,
+            aggregation: Aggregation::MEDIAN,
+        }
+    }
+
+    fn deviation(sources: usize, scenario: &Scenario) -> f64 {
+        worst_deviation(sources, scenario, &mut Rng::new(7))
+    }
+
+    #[test]
+    fn test_honest_spread_bounds_a_minority_of_sources() {
+        assert!(deviation(3, &scenario(0, 1)) <= 0.01);
+        assert!(deviation(5, &scenario(0, 2)) <= 0.01);
+    }
+
+    #[test]
+    fn test_half_the_sources_swing_the_price() {
+        assert!(deviation(2, &scenario(0, 1)) >= 1.0);
+        assert!(deviation(3, &scenario(0, 2)) >= 1.0);
+        assert!(deviation(1, &scenario(0, 1)) >= 1.0);
+    }
+
+    #[test]
+    fn test_validators() {
+        assert!(deviation(3, &scenario(7, 0)) <= 0.01);
+        // 8 of 16 make the even median average in the attack price
+        assert!(deviation(3, &scenario(8, 0)) >= 1.0);
+    }
+
+    #[test]
+    fn test_upper_median_validators() {
+        let upper = Scenario {
+            aggregation: Aggregation {
+                validators: Strategy::UpperMedian,
+                ..Aggregation::MEDIAN
+            },
+            ..scenario(8, 0)
+        };
+        // The upper middle of 16 reports is the 9th smallest: a low attack
+        // by 8 validators leaves it honest, a high one does not.
+        assert!(deviation(1, &upper) >= 1.0);
+    }
+
+    #[test]
+    fn test_outlier_rejection() {
+        let robust = Scenario {
+            aggregation: Aggregation::MEDIAN.reject_outliers(DEFAULT_OUTLIER_CUTOFF),
+            ..scenario(0, 1)
+        };
+        // Rejecting the compromised source keeps the even median honest
+        assert!(deviation(4, &robust) <= 0.01);
+        assert!(deviation(2, &robust) >= 1.0);
+    }
+
+    #[test]
+    fn test_sample_is_distinct() {
+        let mut rng = Rng::new(1);
+        for _ in 0..100 {
+            let mut sample = rng.sample(5, 3);
+            sample.sort();
+            sample.dedup();
+            assert_eq!(sample.len(), 3);
+            assert!(sample.iter().all(|&i| i < 5));
+        }
+    }
+}
@@ -0,0 +1,363 @@
+//! Runs the compiled `.wasm` of an oracle script in a local owasm runtime.
+//!
+//! The runtime is owasm-vm, the one BandChain validators run, so the gas
+//! reported for each phase is what the chain would meter. Requests are
+//! described with [`Host`] from `os_common::host::mock`, the same type the
+//! native simulator uses: prepare records the raw requests it asks for, and
+//! execute answers them from the host's reports.
+//!
+//! The chain only accepts MVP wasm, while the prebuilt std of Rust 1.87 and
+//! later uses bulk memory instructions. Build a script for the runtime with
+//! an older toolchain, e.g.
+//!
+//! ```text
+//! RUSTFLAGS="-C target-cpu=mvp -C link-arg=--compress-relocations" \
+//!     cargo +1.86 build -p <script> --release --target wasm32-unknown-unknown
+//! ```
+//!
+//! then run it with `os-run`, see `src/bin/os-run.rs`.
+//!
+//! `os-plan` runs only prepare and explains each raw request with [`plan`],
+//! see `src/bin/os-plan.rs`.
+//!
+//! [`byzantine`] simulates colluding validators and compromised exchanges
+//! against the median pipeline, see `src/bin/os-byzantine.rs`.
+
+pub mod byzantine;
+pub mod plan;
+mod probestack;
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub use os_common::host::mock::{AskedRequest, Host, Report};
+pub use owasm_vm::Error as VmError;
+
+use owasm_vm::cache::{Cache, CacheOptions};
+use owasm_vm::vm::Querier;
+
+/// Largest calldata, return data or report, in bytes, the runtime passes
+/// between host and script unless [`Runtime::span_size`] says otherwise.
+pub const DEFAULT_SPAN_SIZE: i64 = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Prepare,
+    Execute,
+}
+
+/// A compiled script, ready to run either phase.
+pub struct Runtime {
+    code: Vec<u8>,
+    cache: Cache,
+    pub span_size: i64,
+}
+
+impl Runtime {
+    /// Validates and instruments `wasm` the way the chain does when the
+    /// script is uploaded.
+    pub fn new(wasm: &[u8]) -> Result<Runtime, VmError> {
+        Ok(Runtime {
+            code: owasm_vm::compile(wasm)?,
+            cache: Cache::new(CacheOptions { cache_size: 1 }),
+            span_size: DEFAULT_SPAN_SIZE,
+        })
+    }
+
+    /// Runs one phase with at most `gas_limit` gas. Returns the host with
+    /// what the phase recorded, and the gas used.
+    ///
+    /// The execute phase answers only the raw requests in `host.asked`, so
+    /// pass it the host returned by prepare.
+    pub fn run(
+        &mut self,
+        phase: Phase,
+        host: Host,
+        gas_limit: u64,
+    ) -> (Host, Result<u64, VmError>) {
+        let host = Arc::new(Mutex::new(host));
+        let querier = HostQuerier {
+            host: host.clone(),
+            phase,
+            span_size: self.span_size,
+        };
+        let gas_used = owasm_vm::run(
+            &mut self.cache,
+            &self.code,
+            gas_limit,
+            phase == Phase::Prepare,
+            querier,
+        );
+        let host = host.lock().unwrap().clone();
+        (host, gas_used)
+    }
+}
+
+/// Answers the owasm host calls of one phase from a [`Host`], failing calls
+/// that are not allowed in the phase the way the chain does.
+struct HostQuerier {
+    host: Arc<Mutex<Host>>,
+    phase: Phase,
+    span_size: i64,
+}
+
+impl HostQuerier {
+    fn host(&self) -> MutexGuard<'_, Host> {
+        self.host.lock().unwrap()
+    }
+
+    fn only_in(&self, phase: Phase) -> Result<(), VmError> {
+        if self.phase == phase {
+            Ok(())
+        } else {
+            Err(VmError::WrongPeriodActionError)
+        }
+    }
+
+    fn check_external_id(&self, eid: i64, vid: i64) -> Result<(), VmError> {
+        self.only_in(Phase::Execute)?;
+        let host = self.host();
+        if vid < 0 || vid >= host.ask_count {
+            return Err(VmError::BadValidatorIndexError);
+        }
+        if !host.asked.iter().any(|request| request.eid == eid) {
+            return Err(VmError::BadExternalIDError);
+        }
+        Ok(())
+    }
+}
+
+impl Querier for HostQuerier {
+    fn get_span_size(&self) -> i64 {
+        self.span_size
+    }
+
+    fn get_calldata(&self) -> Result<Vec<u8>, VmError> {
+        Ok(self.host().calldata.clone())
+    }
+
+    fn set_return_data(&self, data: &[u8]) -> Result<(), VmError> {
+        self.only_in(Phase::Execute)?;
+        let mut host = self.host();
+        if host.return_data.is_some() {
+            return Err(VmError::RepeatSetReturnDataError);
+        }
+        host.return_data = Some(data.to_vec());
+        Ok(())
+    }
+
+    fn get_ask_count(&self) -> i64 {
+        self.host().ask_count
+    }
+
+    fn get_min_count(&self) -> i64 {
+        self.host().min_count
+    }
+
+    fn get_prepare_time(&self) -> i64 {
+        self.host().prepare_time
+    }
+
+    fn get_execute_time(&self) -> Result<i64, VmError> {
+        self.only_in(Phase::Execute)?;
+        Ok(self.host().execute_time)
+    }
+
+    fn get_ans_count(&self) -> Result<i64, VmError> {
+        self.only_in(Phase::Execute)?;
+        Ok(self.host().ans_count())
+    }
+
+    fn ask_external_data(&self, eid: i64, did: i64, data: &[u8]) -> Result<(), VmError> {
+        self.only_in(Phase::Prepare)?;
+        let mut host = self.host();
+        if host.asked.iter().any(|request| request.eid == eid) {
+            return Err(VmError::DuplicateExternalIDError);
+        }
+        host.asked.push(AskedRequest {
+            eid,
+            did,
+            calldata: data.to_vec(),
+        });
+        Ok(())
+    }
+
+    fn get_external_data_status(&self, eid: i64, vid: i64) -> Result<i64, VmError> {
+        self.check_external_id(eid, vid)?;
+        Ok(self.host().external_data(eid, vid).err().unwrap_or(0))
+    }
+
+    fn get_external_data(&self, eid: i64, vid: i64) -> Result<Vec<u8>, VmError> {
+        self.check_external_id(eid, vid)?;
+        self.host()
+            .external_data(eid, vid)
+            .map(String::into_bytes)
+            .map_err(|_| VmError::UnavailableExternalDataError)
+    }
+}
+
+/// Describes why a phase failed: the runtime error, followed by the
+/// [`ScriptError`](os_common::ScriptError) report the script left as return
+/// data, if any. Scripts only leave one in execute, as the chain refuses
+/// return data in prepare.
+pub fn describe_failure(host: &Host, err: VmError) -> String {
+    let report = host
+        .return_data
+        .as_deref()
+        .and_then(|data| std::str::from_utf8(data).ok())
+        .filter(|report| os_common::ScriptError::code_from_report(report).is_some());
+    match report {
+        Some(report) => format!("{} ({})", err, report),
+        None => err.to_string(),
+    }
+}
+
+/// Calldata of the price scripts, whose input is `{symbols, multiplier}`.
+pub fn price_calldata(symbols: &[String], multiplier: u64) -> Vec<u8> {
+    use obi::OBIEncode;
+
+    let mut calldata = symbols.to_vec().try_to_vec().unwrap();
+    calldata.extend(multiplier.try_to_vec().unwrap());
+    calldata
+}
+
+/// Answers every raw request of a price script with `price` once per symbol
+/// in its calldata, from every validator. A leading lowercase word is the
+/// CCXT exchange name and is skipped.
+pub fn price_reports(mut host: Host, price: &str) -> Host {
+    for request in host.asked.clone() {
+        let calldata = String::from_utf8_lossy(&request.calldata).into_owned();
+        let mut words: Vec<&str> = calldata.split(' ').collect();
+        if words.len() > 1 && words[0].chars().all(|c| c.is_ascii_lowercase()) {
+            words.remove(0);
+        }
+        let report = vec![price; words.len()].join(",");
+        let reports = vec![report; host.ask_count as usize];
+        host = host.reports(request.eid, reports);
+    }
+    host
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Asks for external ID 1 in prepare; in execute, returns the report of
+    // validator 0 for it, or "none" if that validator failed.
+    const ECHO: &str = r#"(module
+        (import "env" "ask_external_data" (func $ask (param i64 i64 i64 i64)))
+        (import "env" "get_external_data_status" (func $status (param i64 i64) (result i64)))
+        (import "env" "read_external_data" (func $read (param i64 i64 i64) (result i64)))
+        (import "env" "set_return_data" (func $ret (param i64 i64)))
+        (memory (export "memory") 1)
+        (data (i32.const 0) "BTC")
+        (data (i32.const 16) "none")
+        (func (export "prepare")
+            (call $ask (i64.const 1) (i64.const 7) (i64.const 0) (i64.const 3)))
+        (func (export "execute")
+            (if (i64.eqz (call $status (i64.const 1) (i64.const 0)))
+                (then (call $ret (i64.const 32)
+                    (call $read (i64.const 1) (i64.const 0) (i64.const 32))))
+                (else (call $ret (i64.const 16) (i64.const 4))))))"#;
+
+    fn echo() -> Runtime {
+        Runtime::new(&wat::parse_str(ECHO).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_prepare_records_raw_requests() {
+        let (host, gas_used) = echo().run(Phase::Prepare, Host::new(vec![]), u64::MAX);
+        assert!(gas_used.unwrap() > 0);
+        assert_eq!(
+            host.asked,
+            vec![AskedRequest {
+                eid: 1,
+                did: 7,
+                calldata: b"BTC".to_vec(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_execute_replays_reports() {
+        let mut runtime = echo();
+        let (host, _) = runtime.run(Phase::Prepare, Host::new(vec![]), u64::MAX);
+
+        let (answered, gas_used) =
+            runtime.run(Phase::Execute, host.clone().reports(1, ["42"]), u64::MAX);
+        assert!(gas_used.is_ok());
+        assert_eq!(answered.return_data, Some(b"42".to_vec()));
+
+        let failed = host.report(1, 0, Report::Failed(1));
+        let (failed, _) = runtime.run(Phase::Execute, failed, u64::MAX);
+        assert_eq!(failed.return_data, Some(b"none".to_vec()));
+    }
+
+    #[test]
+    fn test_phase_rules() {
+        let mut runtime = echo();
+        // No raw request was asked for external ID 1
+        let (_, gas_used) = runtime.run(Phase::Execute, Host::new(vec![]), u64::MAX);
+        assert_eq!(gas_used, Err(VmError::BadExternalIDError));
+    }
+
+    #[test]
+    fn test_out_of_gas() {
+        let (_, gas_used) = echo().run(Phase::Prepare, Host::new(vec![]), 1);
+        assert_eq!(gas_used, Err(VmError::OutOfGasError));
+    }
+
+    // Fails both phases the way the os-common entry points do: traps in
+    // prepare, and hands the report to the host before trapping in execute.
+    const FAILING: &str = r#"(module
+        (import "env" "set_return_data" (func $ret (param i64 i64)))
+        (memory (export "memory") 1)
+        (data (i32.const 0) "script error 302: no price for symbol BTC")
+        (func (export "prepare")
+            unreachable)
+        (func (export "execute")
+            (call $ret (i64.const 0) (i64.const 41))
+            unreachable))"#;
+
+    #[test]
+    fn test_describe_failure() {
+        let mut runtime = Runtime::new(&wat::parse_str(FAILING).unwrap()).unwrap();
+        let err = VmError::RuntimeError;
+        let (host, gas_used) = runtime.run(Phase::Prepare, Host::new(vec![]), u64::MAX);
+        assert_eq!(gas_used, Err(err));
+        assert_eq!(describe_failure(&host, err), "RuntimeError");
+        let (host, gas_used) = runtime.run(Phase::Execute, Host::new(vec![]), u64::MAX);
+        assert_eq!(gas_used, Err(err));
+        assert_eq!(
+            describe_failure(&host, err),
+            "RuntimeError (script error 302: no price for symbol BTC)"
+        );
+
+        let (host, gas_used) = echo().run(Phase::Execute, Host::new(vec![]), u64::MAX);
+        assert_eq!(
+            describe_failure(&host, gas_used.unwrap_err()),
+            "BadExternalIDError"
+        );
+    }
+
+    #[test]
+    fn test_price_reports() {
+        let host = Host {
+            asked: vec![
+                AskedRequest {
+                    eid: 0,
+                    did: 3,
+                    calldata: b"binance BTC ETH".to_vec(),
+                },
+                AskedRequest {
+                    eid: 1,
+                    did: 11,
+                    calldata: b"aUST bLUNA".to_vec(),
+                },
+            ],
+            ..Host::new(vec![]).ask_count(2)
+        };
+        let host = price_reports(host, "1.5");
+        assert_eq!(host.external_data(0, 1), Ok("1.5,1.5".into()));
+        assert_eq!(host.external_data(1, 0), Ok("1.5,1.5".into()));
+    }
+}
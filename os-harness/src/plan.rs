@@ -0,0 +1,163 @@
+//! Explains the raw requests the prepare phase of a script asks for.
+//!
+//! The raw requests themselves are the ones the compiled script records
+//! when run, so they are exactly what the chain would see. [`Sources`] adds
+//! what the script's configuration says about each: the exchange behind an
+//! external ID of a price script, and the provider a VRF script picked.
+
+use crate::AskedRequest;
+use os_build::coverage::{ExchangeColumn, COVERAGE_FILE};
+use os_build::{available_networks, default_network, Coverage, Profile, VrfProvider};
+use std::path::Path;
+
+/// What a script's configuration tells about its raw requests.
+#[derive(Debug, Default)]
+pub struct Sources {
+    /// Exchange columns of a price script, by external ID.
+    pub exchanges: Vec<ExchangeColumn>,
+    /// VRF providers of the network, in selection order.
+    pub vrf: Vec<VrfProvider>,
+}
+
+impl Sources {
+    /// Reads the coverage table of the script in `script_dir`, and its
+    /// `network` profile if it has profiles. Without a `network`, the
+    /// profile is the one the script builds against by default, see
+    /// [`default_network`].
+    pub fn load(script_dir: &Path, network: Option<&str>) -> Result<Sources, String> {
+        let mut sources = Sources::default();
+        let coverage_path = script_dir.join(COVERAGE_FILE);
+        if coverage_path.exists() {
+            sources.exchanges = Coverage::load(&coverage_path)
+                .map_err(|e| format!("{}: {}", coverage_path.display(), e))?
+                .exchanges;
+        }
+        let profiles_dir = script_dir.join("profiles");
+        if profiles_dir.exists() {
+            let network = match network {
+                Some(network) => network.to_string(),
+                None => default_network(script_dir)?,
+            };
+            let path = profiles_dir.join(format!("{}.toml", network));
+            if !path.exists() {
+                return Err(format!(
+                    "{}: no `{}` network profile, available profiles: {}",
+                    script_dir.display(),
+                    network,
+                    available_networks(&profiles_dir).join(", ")
+                ));
+            }
+            sources.vrf = Profile::load(&path)
+                .map_err(|e| format!("{}: {}", path.display(), e))?
+                .vrf;
+        }
+        Ok(sources)
+    }
+
+    /// Names what answers `request`: the exchange, and whether it is reached
+    /// through CCXT, or the VRF provider and its selection index.
+    pub fn describe(&self, request: &AskedRequest) -> Option<String> {
+        if let Some(exchange) = usize::try_from(request.eid)
+            .ok()
+            .and_then(|eid| self.exchanges.get(eid))
+        {
+            return Some(match exchange.api {
+                true => exchange.name.clone(),
+                false => format!("{} via CCXT", exchange.name),
+            });
+        }
+        let index = self
+            .vrf
+            .iter()
+            .position(|provider| provider.data_source_id == request.did)?;
+        Some(format!(
+            "{}, index {} of {} providers",
+            self.vrf[index].name,
+            index,
+            self.vrf.len()
+        ))
+    }
+
+    /// One line per raw request, under a header: external ID, data source
+    /// ID, source as described by [`Sources::describe`], and calldata.
+    pub fn render(&self, asked: &[AskedRequest]) -> Vec<String> {
+        let described: Vec<String> = asked
+            .iter()
+            .map(|request| self.describe(request).unwrap_or_else(|| "-".into()))
+            .collect();
+        let width = described.iter().map(String::len).max().unwrap_or(0).max(6);
+        let mut lines = vec![format!(
+            "{:>4}  {:>4}  {:<width$}  calldata",
+            "eid", "did", "source"
+        )];
+        for (request, source) in asked.iter().zip(described) {
+            lines.push(format!(
+                "{:>4}  {:>4}  {:<width$}  {}",
+                request.eid,
+                request.did,
+                source,
+                String::from_utf8_lossy(&request.calldata)
+            ));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn script_dir(script: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../scripts")
+            .join(script)
+    }
+
+    fn asked(eid: i64, did: i64, calldata: &str) -> AskedRequest {
+        AskedRequest {
+            eid,
+            did,
+            calldata: calldata.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_price_script_sources() {
+        let sources = Sources::load(
+            &script_dir("standard_dataset_crypto_mainnet"),
+            Some("mainnet"),
+        )
+        .unwrap();
+        assert_eq!(
+            sources.render(&[asked(1, 1, "BTC ETH"), asked(5, 3, "huobipro BTC")]),
+            vec![
+                " eid   did  source             calldata",
+                "   1     1  CRYPTOCOMPARE      BTC ETH",
+                "   5     3  HUOBIPRO via CCXT  huobipro BTC",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_vrf_script_sources() {
+        let sources = Sources::load(&script_dir("vrf_rsds"), Some("testnet")).unwrap();
+        assert_eq!(
+            sources.describe(&asked(1, 83, "6d756d75 1234")),
+            Some("VRF2, index 1 of 4 providers".into())
+        );
+        assert_eq!(sources.describe(&asked(1, 95, "6d756d75 1234")), None);
+        assert!(Sources::load(&script_dir("vrf_rsds"), Some("nowhere"))
+            .unwrap_err()
+            .ends_with("available profiles: mainnet, testnet"));
+    }
+
+    #[test]
+    fn test_sources_default_to_the_build_network() {
+        // vrf_v3 has no mainnet profile, it defaults to v3-testnet
+        let sources = Sources::load(&script_dir("vrf_v3"), None).unwrap();
+        let v3_testnet = Sources::load(&script_dir("vrf_v3"), Some("v3-testnet")).unwrap();
+        assert_eq!(sources.vrf.len(), v3_testnet.vrf.len());
+        assert_eq!(sources.vrf[0].name, v3_testnet.vrf[0].name);
+    }
+}
@@ -0,0 +1,39 @@
+//! `__rust_probestack` for wasmer 2, which owasm-vm is built on.
+//!
+//! wasmer-vm 2.3 links the stack probe of Rust's compiler-builtins on x86-64,
+//! which newer toolchains no longer export. This is the compiler-builtins
+//! implementation, defined weak so an exported one still wins.
+
+#[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+std::arch::global_asm!(
+    ".text",
+    ".weak __rust_probestack",
+    ".type __rust_probestack, @function",
+    "__rust_probestack:",
+    ".cfi_startproc",
+    "pushq %rbp",
+    ".cfi_adjust_cfa_offset 8",
+    ".cfi_offset %rbp, -16",
+    "movq %rsp, %rbp",
+    ".cfi_def_cfa_register %rbp",
+    "mov %rax, %r11",
+    "cmp $0x1000, %r11",
+    "jna 3f",
+    "2:",
+    "sub $0x1000, %rsp",
+    "test %rsp, 8(%rsp)",
+    "sub $0x1000, %r11",
+    "cmp $0x1000, %r11",
+    "ja 2b",
+    "3:",
+    "sub %r11, %rsp",
+    "test %rsp, 8(%rsp)",
+    "add %rax, %rsp",
+    "leave",
+    ".cfi_def_cfa_register %rsp",
+    ".cfi_adjust_cfa_offset -8",
+    "ret",
+    ".cfi_endproc",
+    ".size __rust_probestack, . - __rust_probestack",
+    options(att_syntax)
+);
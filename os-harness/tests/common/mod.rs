@@ -0,0 +1,49 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Toolchain whose prebuilt std is still MVP wasm, see the crate docs.
+const MVP_TOOLCHAIN: &str = "1.86";
+
+/// Builds `script` with `features` for the chain and returns its `.wasm`, or
+/// `None` when the MVP toolchain is not installed.
+pub fn build_wasm(script: &str, features: &[&str]) -> Option<Vec<u8>> {
+    let installed = Command::new("rustup")
+        .args(["run", MVP_TOOLCHAIN, "rustc", "--version"])
+        .output()
+        .is_ok_and(|output| output.status.success());
+    if !installed {
+        eprintln!(
+            "skipped: toolchain {} is not installed, see the os_harness docs",
+            MVP_TOOLCHAIN
+        );
+        return None;
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    // One target directory per feature set, so builds do not overwrite each
+    // other's .wasm
+    let target_dir = match features.is_empty() {
+        true => root.join("target/mvp"),
+        false => root.join(format!("target/mvp-{}", features.join("-"))),
+    };
+    let status = Command::new("rustup")
+        .args(["run", MVP_TOOLCHAIN, "cargo", "build", "-q", "--release"])
+        .args(["-p", script, "--target", "wasm32-unknown-unknown"])
+        .args(["--features", &features.join(",")])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .current_dir(&root)
+        .env(
+            "RUSTFLAGS",
+            "-C target-cpu=mvp -C link-arg=--compress-relocations",
+        )
+        .env_remove("BAND_NETWORK")
+        .status()
+        .unwrap();
+    assert!(status.success(), "building {} failed", script);
+
+    let wasm: PathBuf = target_dir
+        .join("wasm32-unknown-unknown/release")
+        .join(format!("{}.wasm", script));
+    Some(std::fs::read(wasm).unwrap())
+}
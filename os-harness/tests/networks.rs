@@ -0,0 +1,62 @@
+//! The unit tests and replay fixtures of a script with network profiles run
+//! against every one of its profiles, not only the default one that
+//! `cargo test --workspace` builds.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Scripts to test under each network other than their default one.
+fn other_networks(scripts_dir: &Path) -> BTreeMap<String, Vec<String>> {
+    let mut scripts_by_network: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for entry in fs::read_dir(scripts_dir).unwrap() {
+        let script_dir = entry.unwrap().path();
+        let profiles_dir = script_dir.join("profiles");
+        if !profiles_dir.is_dir() {
+            continue;
+        }
+        let default = os_build::default_network(&script_dir).unwrap();
+        let script = script_dir.file_name().unwrap().to_string_lossy();
+        for network in os_build::available_networks(&profiles_dir) {
+            if network != default {
+                let scripts = scripts_by_network.entry(network).or_default();
+                scripts.push(script.to_string());
+            }
+        }
+    }
+    scripts_by_network
+}
+
+#[test]
+fn test_scripts_under_every_network() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let scripts_by_network = other_networks(&root.join("scripts"));
+    assert!(scripts_by_network.contains_key("testnet4"));
+
+    let mut failed = Vec::new();
+    for (network, scripts) in scripts_by_network.iter() {
+        let mut cargo = Command::new(env!("CARGO"));
+        cargo.args(["test", "-q", "--lib"]);
+        for script in scripts {
+            cargo.args(["-p", script]);
+        }
+        // Only the scripts rebuild from one network to the next, and the
+        // workspace build is left alone
+        let status = cargo
+            .arg("--target-dir")
+            .arg(root.join("target/networks"))
+            .current_dir(&root)
+            .env("BAND_NETWORK", network)
+            .status()
+            .unwrap();
+        if !status.success() {
+            failed.push(format!("{} ({})", network, scripts.join(", ")));
+        }
+    }
+    assert!(
+        failed.is_empty(),
+        "tests failed under {}",
+        failed.join("; ")
+    );
+}
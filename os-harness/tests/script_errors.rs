@@ -0,0 +1,36 @@
+//! A script failing in execute under owasm-vm, built the way the chain runs
+//! it, leaves its `ScriptError` report where the runtime can read the code.
+//! In prepare, where the chain refuses return data, it only aborts.
+
+mod common;
+
+use os_common::ScriptError;
+use os_harness::{price_calldata, Host, Phase, Runtime, VmError};
+
+fn code(host: &Host) -> Option<u16> {
+    let report = std::str::from_utf8(host.return_data.as_deref()?).ok()?;
+    ScriptError::code_from_report(report)
+}
+
+#[test]
+fn test_failed_phases_report_their_code() {
+    let wasm = match common::build_wasm("standard_dataset_forex_injective", &[]) {
+        Some(wasm) => wasm,
+        None => return,
+    };
+    let mut runtime = Runtime::new(&wasm).unwrap();
+
+    let unknown = price_calldata(&["DOGE".to_string()], 100);
+    let (host, gas_used) = runtime.run(Phase::Prepare, Host::new(unknown), u64::MAX);
+    assert_eq!(gas_used, Err(VmError::RuntimeError));
+    assert_eq!(host.return_data, None);
+
+    let (host, gas_used) = runtime.run(Phase::Execute, Host::new(vec![0xff]), u64::MAX);
+    assert_eq!(gas_used, Err(VmError::RuntimeError));
+    assert_eq!(code(&host), Some(100));
+
+    let known = price_calldata(&["EUR".to_string()], 100);
+    let (host, gas_used) = runtime.run(Phase::Prepare, Host::new(known), u64::MAX);
+    assert!(gas_used.is_ok());
+    assert_eq!(host.return_data, None);
+}
@@ -0,0 +1,52 @@
+//! The largest request the price scripts support fits in the span the
+//! runtime passes calldata and return data in, in both builds.
+
+mod common;
+
+use os_harness::{price_calldata, price_reports, Host, Phase, Runtime, DEFAULT_SPAN_SIZE};
+
+/// Symbols in the largest request the price scripts support.
+const LARGEST_REQUEST: usize = 80;
+
+fn largest_request() -> Vec<String> {
+    let symbols = [
+        "BTC", "ETH", "ADA", "DOT", "SOL", "XRP", "LINK", "UNI", "AAVE", "ATOM",
+    ];
+    symbols
+        .iter()
+        .cycle()
+        .take(LARGEST_REQUEST)
+        .map(|symbol| symbol.to_string())
+        .collect()
+}
+
+fn return_data(wasm: &[u8]) -> Vec<u8> {
+    let mut runtime = Runtime::new(wasm).unwrap();
+    assert_eq!(runtime.span_size, DEFAULT_SPAN_SIZE);
+    let calldata = price_calldata(&largest_request(), 1_000_000_000);
+    assert!(calldata.len() <= DEFAULT_SPAN_SIZE as usize);
+
+    let (host, gas_used) = runtime.run(Phase::Prepare, Host::new(calldata), u64::MAX);
+    assert!(gas_used.is_ok(), "prepare failed: {:?}", gas_used);
+    let host = price_reports(host, "12345.678901");
+    let (host, gas_used) = runtime.run(Phase::Execute, host, u64::MAX);
+    assert!(gas_used.is_ok(), "execute failed: {:?}", gas_used);
+    host.return_data.unwrap()
+}
+
+#[test]
+fn test_largest_request_fits_the_span() {
+    for features in [&[][..], &["partial"]] {
+        let wasm = match common::build_wasm("linear_os", features) {
+            Some(wasm) => wasm,
+            None => return,
+        };
+        let output = return_data(&wasm);
+        assert!(
+            output.len() <= DEFAULT_SPAN_SIZE as usize,
+            "{:?} output is {} bytes",
+            features,
+            output.len()
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! A BTC rate at 1e18 precision is exact in the `wide` build of a price
+//! script under owasm-vm, and fails with `RateOverflow` in the default build,
+//! whose output encodes rates as u64.
+
+mod common;
+
+use obi::OBIDecode;
+use os_common::ScriptError;
+use os_harness::{price_calldata, price_reports, Host, Phase, Runtime};
+
+const MULTIPLIER: u64 = 1_000_000_000_000_000_000;
+
+fn execute(wasm: &[u8]) -> (Host, bool) {
+    let mut runtime = Runtime::new(wasm).unwrap();
+    let calldata = price_calldata(&["BTC".to_string()], MULTIPLIER);
+    let (host, gas_used) = runtime.run(Phase::Prepare, Host::new(calldata), u64::MAX);
+    assert!(gas_used.is_ok(), "prepare failed: {:?}", gas_used);
+    let host = price_reports(host, "67234.1");
+    let (host, gas_used) = runtime.run(Phase::Execute, host, u64::MAX);
+    (host, gas_used.is_ok())
+}
+
+#[test]
+fn test_btc_rate_at_1e18() {
+    let script = "standard_dataset_crypto_mainnet";
+    let wasm = match common::build_wasm(script, &["wide"]) {
+        Some(wasm) => wasm,
+        None => return,
+    };
+    let (host, ok) = execute(&wasm);
+    assert!(ok, "execute failed: {:?}", host.return_data);
+    let rates = Vec::<u128>::try_from_slice(&host.return_data.unwrap()).unwrap();
+    assert_eq!(rates, vec![67_234_100_000_000_000_000_000]);
+
+    let wasm = common::build_wasm(script, &[]).unwrap();
+    let (host, ok) = execute(&wasm);
+    assert!(!ok);
+    let report = String::from_utf8(host.return_data.unwrap()).unwrap();
+    assert_eq!(ScriptError::code_from_report(&report), Some(304));
+}
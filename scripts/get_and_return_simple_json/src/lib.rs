@@ -0,0 +1,59 @@
+use obi::{OBIDecode, OBIEncode, OBISchema};
+use os_common::host::{execute_entry_point, ext, oei, prepare_entry_point};
+use os_common::ScriptError;
+
+const DS:i64 = 263;
+
+#[derive(OBIDecode, OBISchema)]
+struct Input {
+    sliced_index_input: i8
+}
+
+#[derive(OBIEncode, OBISchema)]
+struct Output {
+    result: String,
+}
+
+/// The on-chain `{input}/{output}` schema, recorded in schemas.json.
+pub fn schema() -> String {
+    os_common::obi_schema::<Input, Output>()
+}
+
+fn prepare_impl(input: Input) -> Result<(), ScriptError> {
+    oei::ask_external_data(1, DS, format!("{}", input.sliced_index_input).as_bytes());
+    Ok(())
+}
+
+#[no_mangle]
+fn execute_impl(_input: Input) -> Result<Output, ScriptError> {
+    Ok(Output { result: ext::require_majority::<String>(1)? })
+}
+
+prepare_entry_point!(prepare_impl);
+execute_entry_point!(execute_impl);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_common::host::replay::replay_fixtures;
+    use os_common::schema::check_schema;
+
+    #[test]
+    fn test_fixtures() {
+        replay_fixtures(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"),
+            env!("CARGO_PKG_NAME"),
+            prepare,
+            execute,
+        );
+    }
+
+    #[test]
+    fn test_schema() {
+        check_schema(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../schemas.json"),
+            env!("CARGO_PKG_NAME"),
+            &schema(),
+        );
+    }
+}
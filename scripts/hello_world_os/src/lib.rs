@@ -0,0 +1,53 @@
+use obi::{OBIDecode, OBIEncode, OBISchema};
+use os_common::host::{execute_entry_point, ext, oei, prepare_entry_point};
+use os_common::ScriptError;
+
+#[derive(OBIDecode, OBISchema)]
+struct Input { word: String }
+
+#[derive(OBIEncode, OBISchema)]
+struct Output { result: String }
+
+/// The on-chain `{input}/{output}` schema, recorded in schemas.json.
+pub fn schema() -> String {
+    os_common::obi_schema::<Input, Output>()
+}
+
+fn prepare_impl(input: Input) -> Result<(), ScriptError> {
+    if input.word.is_empty() { return Err(ScriptError::InvalidInput("word is empty".into())); }
+    oei::ask_external_data(1, 6, input.word.as_bytes());
+    Ok(())
+}
+
+fn execute_impl(_: Input) -> Result<Output, ScriptError> {
+    Ok(Output { result: ext::require_majority::<String>(1)? })
+}
+
+prepare_entry_point!(prepare_impl);
+execute_entry_point!(execute_impl);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_common::host::replay::replay_fixtures;
+    use os_common::schema::check_schema;
+
+    #[test]
+    fn test_fixtures() {
+        replay_fixtures(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"),
+            env!("CARGO_PKG_NAME"),
+            prepare,
+            execute,
+        );
+    }
+
+    #[test]
+    fn test_schema() {
+        check_schema(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../schemas.json"),
+            env!("CARGO_PKG_NAME"),
+            &schema(),
+        );
+    }
+}
@@ -0,0 +1,417 @@
+use obi::{OBIDecode, OBIEncode, OBISchema};
+use os_common::host::{execute_entry_point, ext, oei, prepare_entry_point};
+use os_common::report::parse_integers;
+use os_common::{RateStatus, ScriptError, Strategy};
+use phf::phf_map;
+
+#[derive(OBIDecode, OBISchema)]
+#[cfg_attr(test, derive(OBIEncode))]
+struct Input {
+    symbols: Vec<String>,
+}
+
+#[derive(OBIEncode, OBISchema)]
+#[cfg_attr(test, derive(OBIDecode))]
+struct Output {
+    rates: Vec<u64>,
+}
+
+/// Output of the `partial` build, in which a symbol without a rate does not
+/// fail the request.
+#[derive(OBIEncode, OBISchema)]
+#[cfg_attr(test, derive(OBIDecode))]
+struct PartialOutput {
+    rates: Vec<u64>,
+    /// Status code of each rate, see [`RateStatus`]. A rate is 0 unless its
+    /// status is 0.
+    statuses: Vec<u8>,
+}
+
+/// The on-chain `{input}/{output}` schema of this build, recorded in
+/// schemas.json.
+pub fn schema() -> String {
+    match cfg!(feature = "partial") {
+        true => os_common::obi_schema::<Input, PartialOutput>(),
+        false => os_common::obi_schema::<Input, Output>(),
+    }
+}
+
+const DS_ID: i64 = 1;
+/// Combines the rates validators report, always one of them.
+const AGGREGATION: Strategy = Strategy::UpperMedian;
+
+static SYMBOLS: phf::Map<&'static str, bool> = phf_map! {
+    "1INCH" => true,
+    "AAVE" => true,
+    "ADA" => true,
+    "ALCX" => true,
+    "ALGO" => true,
+    "ALPHA" => true,
+    "ARB" => true,
+    "ASTR" => true,
+    "ATOM" => true,
+    "AVAX" => true,
+    "BAND" => true,
+    "BAT" => true,
+    "BETA" => true,
+    "BNB" => true,
+    "BTC" => true,
+    "BTT" => true,
+    "BUSD" => true,
+    "C98" => true,
+    "CAKE" => true,
+    "CELO" => true,
+    "CKB" => true,
+    "CLV" => true,
+    "COMP" => true,
+    "CREAM" => true,
+    "CRO" => true,
+    "CRV" => true,
+    "CUSD" => true,
+    "DAI" => true,
+    "DOGE" => true,
+    "DOT" => true,
+    "ETH" => true,
+    "FRAX" => true,
+    "FTM" => true,
+    "GLMR" => true,
+    "HEGIC" => true,
+    "ICX" => true,
+    "INJ" => true,
+    "JOE" => true,
+    "JST" => true,
+    "KNC" => true,
+    "KP3R" => true,
+    "KSM" => true,
+    "LINK" => true,
+    "LTC" => true,
+    "MANA" => true,
+    "MATIC" => true,
+    "MIM" => true,
+    "MKR" => true,
+    "MOVR" => true,
+    "NFT" => true,
+    "ONE" => true,
+    "OP" => true,
+    "OSMO" => true,
+    "PERP" => true,
+    "ROSE" => true,
+    "SCRT" => true,
+    "SFI" => true,
+    "SHIB" => true,
+    "SNX" => true,
+    "SOL" => true,
+    "SPELL" => true,
+    "STRK" => true,
+    "SUN" => true,
+    "SUSD" => true,
+    "SUSHI" => true,
+    "TRX" => true,
+    "TUSD" => true,
+    "UNI" => true,
+    "USDC" => true,
+    "USDT" => true,
+    "WBTC" => true,
+    "XMR" => true,
+    "XRP" => true,
+    "YFI" => true,
+};
+
+#[cfg_attr(feature = "partial", allow(dead_code))]
+fn prepare_impl(input: Input) -> Result<(), ScriptError> {
+    if input.symbols.is_empty() {
+        return Err(ScriptError::InvalidInput("symbols are empty".into()));
+    }
+    if let Some(symbol) = input
+        .symbols
+        .iter()
+        .find(|s| !SYMBOLS.contains_key(s.as_str()))
+    {
+        return Err(ScriptError::UnknownSymbol(symbol.clone()));
+    }
+
+    oei::ask_external_data(1, DS_ID, input.symbols.join(" ").as_bytes());
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "partial"), allow(dead_code))]
+fn prepare_partial_impl(input: Input) -> Result<(), ScriptError> {
+    if input.symbols.is_empty() {
+        return Err(ScriptError::InvalidInput("symbols are empty".into()));
+    }
+    // Symbols that are not served are left out, unless all of them are
+    let symbols = supported(&input.symbols);
+    if symbols.is_empty() {
+        return Err(ScriptError::UnknownSymbol(input.symbols[0].clone()));
+    }
+
+    oei::ask_external_data(1, DS_ID, symbols.join(" ").as_bytes());
+    Ok(())
+}
+
+fn supported(symbols: &[String]) -> Vec<&str> {
+    symbols
+        .iter()
+        .map(|s| s.as_str())
+        .filter(|s| SYMBOLS.contains_key(s))
+        .collect()
+}
+
+fn aggregate<I>(strings: I, input_len: usize) -> Vec<u64>
+where
+    I: Iterator<Item = String>,
+{
+    strings
+        .filter_map(|s| parse_integers(&s, input_len))
+        .fold(vec![Vec::new(); input_len], |mut acc, v| {
+            for (vec, &num) in acc.iter_mut().zip(&v) {
+                vec.push(num);
+            }
+            acc
+        })
+        .into_iter()
+        .map(|mut nums| AGGREGATION.aggregate_values(&mut nums))
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default()
+}
+
+#[cfg_attr(feature = "partial", allow(dead_code))]
+fn execute_impl(input: Input) -> Result<Output, ScriptError> {
+    let rates: Vec<u64> = aggregate(ext::load_input::<String>(1), input.symbols.len());
+    if rates.len() != input.symbols.len() {
+        return Err(ScriptError::NoReports(1));
+    }
+    // A rate of 0 is never a price
+    if let Some(i) = rates.iter().position(|&rate| rate == 0) {
+        return Err(ScriptError::NoPrice(input.symbols[i].clone()));
+    }
+    Ok(Output { rates })
+}
+
+#[cfg_attr(not(feature = "partial"), allow(dead_code))]
+fn execute_partial_impl(input: Input) -> Result<PartialOutput, ScriptError> {
+    // Empty when no report has a rate for every symbol asked
+    let mut rates = aggregate(
+        ext::load_input::<String>(1),
+        supported(&input.symbols).len(),
+    )
+    .into_iter();
+    let (rates, statuses) = input
+        .symbols
+        .iter()
+        .map(|symbol| {
+            let status = match SYMBOLS.contains_key(symbol.as_str()) {
+                true => match rates.next() {
+                    // A rate of 0 is never a price
+                    Some(rate) if rate > 0 => return (rate, RateStatus::Ok.code()),
+                    _ => RateStatus::NoData,
+                },
+                false => RateStatus::Unsupported,
+            };
+            (0, status.code())
+        })
+        .unzip();
+    Ok(PartialOutput { rates, statuses })
+}
+
+// Tests run the entry points of both builds, see the tests module
+#[cfg(any(test, not(feature = "partial")))]
+prepare_entry_point!(prepare_impl);
+#[cfg(any(test, not(feature = "partial")))]
+execute_entry_point!(execute_impl);
+#[cfg(all(feature = "partial", not(test)))]
+prepare_entry_point!(prepare_partial_impl);
+#[cfg(all(feature = "partial", not(test)))]
+execute_entry_point!(execute_partial_impl);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_common::host::mock::{Host, Report};
+    use os_common::host::replay::replay_fixtures;
+    use os_common::schema::check_schema;
+
+    // The entry points of the `partial` build
+    prepare_entry_point!(prepare_partial_impl as prepare_partial);
+    execute_entry_point!(execute_partial_impl as execute_partial);
+
+    #[test]
+    fn test_fixtures() {
+        replay_fixtures(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"),
+            env!("CARGO_PKG_NAME"),
+            prepare,
+            execute,
+        );
+        replay_fixtures(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"),
+            concat!(env!("CARGO_PKG_NAME"), "+partial"),
+            prepare_partial,
+            execute_partial,
+        );
+    }
+
+    #[test]
+    fn test_1() {
+        let r = aggregate(
+            vec![
+                "12 45 78".to_string(),
+                "32 67 89".to_string(),
+                "54 23 91".to_string(),
+            ]
+            .into_iter(),
+            3,
+        );
+        assert_eq!(r, vec![32, 45, 89])
+    }
+
+    #[test]
+    fn test_2() {
+        let r = aggregate(
+            vec![
+                "xcjkzjkxkx".to_string(),
+                "32 67 89".to_string(),
+                "54 23 91".to_string(),
+            ]
+            .into_iter(),
+            3,
+        );
+        assert_eq!(r, vec![54, 67, 91])
+    }
+
+    #[test]
+    fn test_3() {
+        let r = aggregate(
+            vec![
+                "xcjkzjkxkx".to_string(),
+                "32 67 89".to_string(),
+                "reqfgwegdfdsfs".to_string(),
+            ]
+            .into_iter(),
+            3,
+        );
+        assert_eq!(r, vec![32, 67, 89])
+    }
+
+    #[test]
+    fn test_4() {
+        let r = aggregate(
+            vec![
+                "xcjkzjkxkx".to_string(),
+                "".to_string(),
+                "reqfgwegdfdsfs".to_string(),
+            ]
+            .into_iter(),
+            3,
+        );
+        assert_eq!(r, Vec::<u64>::new())
+    }
+
+    #[test]
+    fn test_5() {
+        let r = aggregate(vec![].into_iter(), 3);
+        assert_eq!(r, Vec::<u64>::new())
+    }
+
+    #[test]
+    fn test_6() {
+        let r = aggregate(vec!["4 1 2 3 5 6".to_string()].into_iter(), 6);
+        assert_eq!(r, vec![4, 1, 2, 3, 5, 6])
+    }
+
+    #[test]
+    fn test_7() {
+        let r = aggregate(
+            vec![
+                "12 x 45 78".to_string(),
+                "32 67 89".to_string(),
+                "54 23 91".to_string(),
+            ]
+            .into_iter(),
+            3,
+        );
+        assert_eq!(r, vec![54, 67, 91])
+    }
+
+    #[test]
+    fn test_end_to_end() {
+        let calldata = Input {
+            symbols: vec!["BTC".into(), "ETH".into()],
+        }
+        .try_to_vec()
+        .unwrap();
+
+        let (host, result) = Host::new(calldata.clone()).run(prepare);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.asked.len(), 1);
+        assert_eq!(host.asked[0].calldata, b"BTC ETH");
+
+        let (host, result) = Host::new(calldata.clone())
+            .ask_count(4)
+            .reports(1, ["100 10", "104 12", "xyz"])
+            .report(1, 3, Report::Failed(1))
+            .run(execute);
+        assert_eq!(result, Ok(()));
+        let output = Output::try_from_slice(&host.return_data.unwrap()).unwrap();
+        assert_eq!(output.rates, vec![104, 12]);
+
+        let (_, result) = Host::new(calldata)
+            .reports(1, ["100 0", "104 0"])
+            .run(execute);
+        assert_eq!(result.unwrap_err().code(), Some(302));
+    }
+
+    #[test]
+    fn test_partial() {
+        let calldata = |symbols: &[&str]| {
+            let symbols = symbols.iter().map(|s| s.to_string()).collect();
+            Input { symbols }.try_to_vec().unwrap()
+        };
+
+        let (_, result) = Host::new(calldata(&["DOGECOINX", "BTC"])).run(prepare);
+        assert_eq!(result.unwrap_err().code(), Some(101));
+
+        // Unknown symbols are not asked for, but keep their position
+        let (host, result) = Host::new(calldata(&["DOGECOINX", "BTC"])).run(prepare_partial);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.asked[0].calldata, b"BTC");
+        let (host, result) = Host::new(calldata(&["DOGECOINX", "BTC"]))
+            .reports(1, ["100"])
+            .run(execute_partial);
+        assert_eq!(result, Ok(()));
+        let output = PartialOutput::try_from_slice(&host.return_data.unwrap()).unwrap();
+        assert_eq!(output.rates, vec![0, 100]);
+        assert_eq!(output.statuses, vec![1, 0]);
+
+        let (host, result) = Host::new(calldata(&["BTC", "ETH"]))
+            .reports(1, ["100 0"])
+            .run(execute_partial);
+        assert_eq!(result, Ok(()));
+        let output = PartialOutput::try_from_slice(&host.return_data.unwrap()).unwrap();
+        assert_eq!(output.rates, vec![100, 0]);
+        assert_eq!(output.statuses, vec![0, 2]);
+
+        let (host, result) = Host::new(calldata(&["BTC"])).run(execute_partial);
+        assert_eq!(result, Ok(()));
+        let output = PartialOutput::try_from_slice(&host.return_data.unwrap()).unwrap();
+        assert_eq!(output.statuses, vec![2]);
+
+        let (_, result) = Host::new(calldata(&["DOGECOINX"])).run(prepare_partial);
+        assert_eq!(result.unwrap_err().code(), Some(101));
+    }
+
+    #[test]
+    fn test_schema() {
+        let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/../../schemas.json");
+        check_schema(
+            manifest,
+            env!("CARGO_PKG_NAME"),
+            &os_common::obi_schema::<Input, Output>(),
+        );
+        check_schema(
+            manifest,
+            concat!(env!("CARGO_PKG_NAME"), "+partial"),
+            &os_common::obi_schema::<Input, PartialOutput>(),
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use os_common::{
+    exchanges, Aggregation, AliasMap, PriceScript, SymbolInfo, SymbolMap, DEFAULT_OUTLIER_CUTOFF,
+};
+use phf::phf_map;
+use strum_macros::{Display, EnumIter, EnumString};
+
+// Data source IDs and token subset of the network selected through
+// `BAND_NETWORK` at build time, see profiles/
+#[allow(dead_code)]
+mod profile {
+    include!(concat!(env!("OUT_DIR"), "/profile.rs"));
+}
+
+// Exchange table and symbol registry, generated from coverage.toml by os-gen
+include!("table.rs");
+
+struct Script;
+
+impl PriceScript for Script {
+    type Exchange = Exchange;
+
+    const EXCHANGE_COUNT: u64 = EXCHANGE_COUNT;
+    const API_SOURCE: &'static [Exchange] = &API_SOURCE;
+    const SYMBOLS: &'static SymbolMap = &SYMBOLS;
+    const ALIASES: &'static AliasMap = &ALIASES;
+    const EXCLUDED_TOKENS: &'static [&'static str] = profile::EXCLUDED_TOKENS;
+    const COVERAGE: &'static [(&'static str, SymbolInfo)] = profile::COVERAGE;
+    const AGGREGATION: Aggregation = Aggregation::MEDIAN.reject_outliers(DEFAULT_OUTLIER_CUTOFF);
+
+    fn data_source_id(exchange: Exchange) -> Option<i64> {
+        profile::data_source_id(&exchange.to_string())
+    }
+}
+
+// Rejected exchanges are listed in the outputs, see os_common::price_script!
+os_common::price_script!(Script, rejected_exchanges);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use obi::{OBIDecode, OBIEncode};
+    use os_common::host::mock::Host;
+
+    fn calldata(symbols: &[&str], multiplier: u64) -> Vec<u8> {
+        let symbols = symbols.iter().map(|s| s.to_string()).collect();
+        Input {
+            symbols,
+            multiplier,
+        }
+        .try_to_vec()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_prepare() {
+        let (host, result) = Host::new(calldata(&["ETH", "btc", "BTC"], 100)).run(prepare);
+        assert_eq!(result, Ok(()));
+        // BINANCE through KRAKEN, in external ID order
+        let eids: Vec<i64> = host.asked.iter().map(|request| request.eid).collect();
+        assert_eq!(eids, (1..12).collect::<Vec<_>>());
+        let binance = &host.asked[0];
+        assert_eq!(Some(binance.did), Script::data_source_id(Exchange::BINANCE));
+        assert_eq!(binance.calldata, b"BTC ETH");
+    }
+
+    #[test]
+    fn test_execute() {
+        let mut host = Host::new(calldata(&["ETH", "BTC"], 100))
+            .ask_count(4)
+            .min_count(3);
+        for eid in 1..12 {
+            host = host.reports(eid, ["100,10", "102,12", "garbage", "101,11"]);
+        }
+        let (host, result) = host.run(execute);
+        assert_eq!(result, Ok(()));
+        let output = Output::<Rate>::try_from_slice(&host.return_data.unwrap()).unwrap();
+        assert_eq!(output.rates, vec![1100, 10100]);
+        assert_eq!(output.rejected_exchanges, vec![]);
+    }
+
+    #[test]
+    fn test_execute_without_reports() {
+        let (_, result) = Host::new(calldata(&["BTC"], 100)).run(execute);
+        assert_eq!(result.unwrap_err().code(), Some(302));
+    }
+
+    #[test]
+    fn test_partial() {
+        // DOGECOINX is not served, so it is not asked for
+        let symbols = ["BTC", "DOGECOINX", "ETH"];
+        let (_, result) = Host::new(calldata(&symbols, 100)).run(prepare);
+        assert_eq!(result.unwrap_err().code(), Some(101));
+        let (host, result) = Host::new(calldata(&symbols, 100)).run(prepare_partial);
+        assert_eq!(result, Ok(()));
+        assert_eq!(host.asked[0].calldata, b"BTC ETH");
+
+        // and no exchange reports ETH
+        let mut host = Host::new(calldata(&symbols, 100));
+        for eid in 1..12 {
+            host = host.reports(eid, ["100,-"]);
+        }
+        let (host, result) = host.run(execute_partial);
+        assert_eq!(result, Ok(()));
+        let output = PartialOutput::<Rate>::try_from_slice(&host.return_data.unwrap()).unwrap();
+        assert_eq!(output.rates, vec![10000, 0, 0]);
+        assert_eq!(output.statuses, vec![0, 1, 2]);
+
+        let (host, result) = Host::new(calldata(&["BTC"], 100)).run(execute_partial);
+        assert_eq!(result, Ok(()));
+        let output = PartialOutput::<Rate>::try_from_slice(&host.return_data.unwrap()).unwrap();
+        assert_eq!(output.rates, vec![0]);
+        assert_eq!(output.statuses, vec![2]);
+    }
+}
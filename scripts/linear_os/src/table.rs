@@ -0,0 +1,125 @@
+// This file was automatically generated by os-gen from coverage.toml. Do not edit.
+// VERSION = 1
+
+const EXCHANGE_COUNT: u64 = 13;
+
+const API_SOURCE: [Exchange; 13] = [
+    Exchange::BIBOX,
+    Exchange::BINANCE,
+    Exchange::BITFINEX,
+    Exchange::BITTREX,
+    Exchange::BRAVENEWCOIN,
+    Exchange::COINBASEPRO,
+    Exchange::COINGECKO,
+    Exchange::COINMARKETCAP,
+    Exchange::CRYPTOCOMPARE,
+    Exchange::HITBTC,
+    Exchange::HUOBIPRO,
+    Exchange::KRAKEN,
+    Exchange::OKX,
+];
+
+#[derive(Display, EnumString, EnumIter, Debug, Copy, Clone, PartialEq)]
+enum Exchange {
+    BIBOX = 0,
+    BINANCE = 1,
+    BITFINEX = 2,
+    BITTREX = 3,
+    BRAVENEWCOIN = 4,
+    COINBASEPRO = 5,
+    COINGECKO = 6,
+    COINMARKETCAP = 7,
+    CRYPTOCOMPARE = 8,
+    HITBTC = 9,
+    HUOBIPRO = 10,
+    KRAKEN = 11,
+    OKX = 12,
+}
+
+static SYMBOLS: SymbolMap = phf_map! {
+    "AAVE" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "ADA" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "ALGO" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "ATOM" => SymbolInfo { exchanges: exchanges![BINANCE, COINBASEPRO, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "AUDIO" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "AVAX" => SymbolInfo { exchanges: exchanges![BINANCE, COINBASEPRO, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO, OKX], min_sources: 1 },
+    "AXS" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "BAL" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "BAT" => SymbolInfo { exchanges: exchanges![BINANCE, BITTREX, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "BCH" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "BNB" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "BORA" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "BTC" => SymbolInfo { exchanges: exchanges![BINANCE, BITFINEX, BITTREX, BRAVENEWCOIN, COINBASEPRO, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HITBTC, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "BTT" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "CAKE" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "CELO" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "COMP" => SymbolInfo { exchanges: exchanges![BINANCE, BITTREX, COINBASEPRO, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, KRAKEN], min_sources: 1 },
+    "CRO" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "CRV" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "DGB" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "DOGE" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "DOT" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "DYDX" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "EGLD" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "ENJ" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "EOS" => SymbolInfo { exchanges: exchanges![BINANCE, BITFINEX, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "ETH" => SymbolInfo { exchanges: exchanges![BINANCE, BITFINEX, BITTREX, BRAVENEWCOIN, COINBASEPRO, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HITBTC, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "FIL" => SymbolInfo { exchanges: exchanges![BIBOX, BINANCE, COINBASEPRO, COINGECKO, COINMARKETCAP, HITBTC, HUOBIPRO], min_sources: 1 },
+    "FTM" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "FTT" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "GALA" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "HT" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "ICX" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "ILV" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "IMX" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "KLAY" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "KNC" => SymbolInfo { exchanges: exchanges![BINANCE, HUOBIPRO], min_sources: 1 },
+    "KSM" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "LEO" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "LINK" => SymbolInfo { exchanges: exchanges![BINANCE, BITTREX, COINBASEPRO, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "LRC" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "LTC" => SymbolInfo { exchanges: exchanges![BINANCE, BITFINEX, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "LUNA" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "MANA" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "MATIC" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "MIOTA" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "MKR" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "MLN" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "MTL" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "NEAR" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HITBTC, HUOBIPRO, OKX], min_sources: 1 },
+    "NEO" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "OKB" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "OMG" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "ONT" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "PNT" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, CRYPTOCOMPARE], min_sources: 1 },
+    "QTUM" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "REN" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "ROSE" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "SAND" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, OKX], min_sources: 1 },
+    "SKL" => SymbolInfo { exchanges: exchanges![BINANCE, COINBASEPRO, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "SNX" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "SOL" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "SRM" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "STX" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "SUSHI" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "SXP" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "THETA" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "TRX" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "UMA" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "UNI" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "VET" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "WEMIX" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "XEM" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "XLM" => SymbolInfo { exchanges: exchanges![BINANCE, COINBASEPRO, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "XPR" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "XRP" => SymbolInfo { exchanges: exchanges![BINANCE, BITFINEX, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "XTZ" => SymbolInfo { exchanges: exchanges![BINANCE, BITFINEX, BITTREX, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "YFI" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+    "YGG" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "ZIL" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "ZRX" => SymbolInfo { exchanges: exchanges![BINANCE, COINGECKO, COINMARKETCAP, CRYPTOCOMPARE, HUOBIPRO], min_sources: 1 },
+};
+
+static ALIASES: AliasMap = phf_map! {
+    "IOTA" => "MIOTA",
+};
@@ -0,0 +1,81 @@
+use os_common::{exchanges, AliasMap, PriceScript, SymbolInfo, SymbolMap};
+use phf::phf_map;
+use strum_macros::{Display, EnumIter, EnumString};
+
+// Data source IDs and token subset of the network selected through
+// `BAND_NETWORK` at build time, see profiles/
+#[allow(dead_code)]
+mod profile {
+    include!(concat!(env!("OUT_DIR"), "/profile.rs"));
+}
+
+// Exchange table and symbol registry, generated from coverage.toml by os-gen
+include!("table.rs");
+
+struct Script;
+
+impl PriceScript for Script {
+    type Exchange = Exchange;
+
+    const EXCHANGE_COUNT: u64 = EXCHANGE_COUNT;
+    const API_SOURCE: &'static [Exchange] = &API_SOURCE;
+    const SYMBOLS: &'static SymbolMap = &SYMBOLS;
+    const ALIASES: &'static AliasMap = &ALIASES;
+    const CCXT_DS_ID: Option<i64> = Some(profile::CCXT_DATA_SOURCE_ID);
+    const EXCLUDED_TOKENS: &'static [&'static str] = profile::EXCLUDED_TOKENS;
+    const COVERAGE: &'static [(&'static str, SymbolInfo)] = profile::COVERAGE;
+
+    fn data_source_id(exchange: Exchange) -> Option<i64> {
+        profile::data_source_id(&exchange.to_string())
+    }
+}
+
+os_common::price_script!(Script);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use obi::OBIEncode;
+    use os_common::exchange_from_u64;
+    use os_common::host::mock::Host;
+
+    #[test]
+    fn test_prepare_asks_profile_data_sources() {
+        let calldata = |symbol: &str| {
+            let input = Input {
+                symbols: vec![symbol.into()],
+                multiplier: 100,
+            };
+            input.try_to_vec().unwrap()
+        };
+        let (host, result) = Host::new(calldata("BTC")).run(prepare);
+        assert_eq!(result, Ok(()));
+        for request in host.asked.iter() {
+            let exchange = exchange_from_u64::<Script>(request.eid as u64).unwrap();
+            let expected = match API_SOURCE.contains(&exchange) {
+                true => profile::data_source_id(&exchange.to_string()),
+                false => Some(profile::CCXT_DATA_SOURCE_ID),
+            };
+            assert_eq!(Some(request.did), expected, "{}", exchange);
+        }
+        // INDEX is served on testnet4 only
+        let (_, result) = Host::new(calldata("INDEX")).run(prepare);
+        assert_eq!(result.is_ok(), profile::NETWORK == "testnet4");
+    }
+
+    #[test]
+    fn test_prepare_uses_profile_coverage() {
+        let input = Input {
+            symbols: vec!["OHM".into()],
+            multiplier: 100,
+        };
+        let (host, result) = Host::new(input.try_to_vec().unwrap()).run(prepare);
+        assert_eq!(result, Ok(()));
+        // OHM is priced from CRYPTOCOMPARE on testnet4 only
+        let asks_cryptocompare = host
+            .asked
+            .iter()
+            .any(|request| request.eid == Exchange::CRYPTOCOMPARE as i64);
+        assert_eq!(asks_cryptocompare, profile::NETWORK == "testnet4");
+    }
+}
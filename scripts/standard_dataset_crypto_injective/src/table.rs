@@ -0,0 +1,96 @@
+// This file was automatically generated by os-gen from coverage.toml. Do not edit.
+
+const EXCHANGE_COUNT: u64 = 19;
+
+const API_SOURCE: [Exchange; 6] = [
+    Exchange::BRAVENEWCOIN,
+    Exchange::CRYPTOCOMPARE,
+    Exchange::COINGECKO,
+    Exchange::COINMARKETCAP,
+    Exchange::COINBASEPRO,
+    Exchange::OSMOSIS,
+];
+
+#[derive(Display, EnumString, EnumIter, Debug, Copy, Clone, PartialEq)]
+enum Exchange {
+    BRAVENEWCOIN = 0,
+    CRYPTOCOMPARE = 1,
+    COINGECKO = 2,
+    COINMARKETCAP = 3,
+    BINANCE = 4,
+    HUOBIPRO = 5,
+    COINBASEPRO = 6,
+    KRAKEN = 7,
+    BITFINEX = 8,
+    BITTREX = 9,
+    BITSTAMP = 10,
+    OKEX = 11,
+    FTX = 12,
+    HITBTC = 13,
+    ITBIT = 14,
+    BITHUMB = 15,
+    COINONE = 16,
+    BIBOX = 17,
+    OSMOSIS = 18,
+}
+
+static SYMBOLS: SymbolMap = phf_map! {
+    "BTC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, KRAKEN, BITFINEX, BITTREX], min_sources: 1 },
+    "ETH" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, KRAKEN, BITFINEX, BITTREX], min_sources: 1 },
+    "USDT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, COINBASEPRO, KRAKEN, BITFINEX, FTX], min_sources: 1 },
+    "INJ" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "BNB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "LUNA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "UST" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, COINBASEPRO, OKEX], min_sources: 1 },
+    "ANC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "ATOM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO], min_sources: 1 },
+    "CRO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "MIR" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "SCRT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "STX" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "OSMO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, OSMOSIS], min_sources: 1 },
+    "MOVR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, KRAKEN], min_sources: 1 },
+    "AVAX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, OKEX], min_sources: 1 },
+    "SOL" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "FTM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "NEAR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, OKEX, HITBTC], min_sources: 1 },
+    "DOGE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "DOT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "ADA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "COMP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, COINBASEPRO, KRAKEN, BITTREX], min_sources: 1 },
+    "HT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "KSM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "LINK" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, KRAKEN, BITTREX], min_sources: 1 },
+    "UNI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "XRP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, BITFINEX], min_sources: 1 },
+    "YFI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "AAVE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "ALCX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, FTX, BIBOX], min_sources: 1 },
+    "ALPHA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, OKEX], min_sources: 1 },
+    "BAL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "BCH" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "CAKE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "CRV" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "EOS" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, BITFINEX], min_sources: 1 },
+    "HBAR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "INDEX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "IOTX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "LTC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, BITFINEX], min_sources: 1 },
+    "MATIC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "OHM" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "PERP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "THETA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "XTZ" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, BITFINEX, BITTREX], min_sources: 1 },
+    "FTT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "ZIL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "EGLD" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "HNT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "KAI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "KDA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "ONE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "TOMO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "FIL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, OKEX], min_sources: 1 },
+};
+
+static ALIASES: AliasMap = phf_map! {
+};
@@ -0,0 +1,33 @@
+use os_common::{exchanges, AliasMap, PriceScript, SymbolInfo, SymbolMap};
+use phf::phf_map;
+use strum_macros::{Display, EnumIter, EnumString};
+
+// Data source IDs and token subset of the network selected through
+// `BAND_NETWORK` at build time, see profiles/
+#[allow(dead_code)]
+mod profile {
+    include!(concat!(env!("OUT_DIR"), "/profile.rs"));
+}
+
+// Exchange table and symbol registry, generated from coverage.toml by os-gen
+include!("table.rs");
+
+struct Script;
+
+impl PriceScript for Script {
+    type Exchange = Exchange;
+
+    const EXCHANGE_COUNT: u64 = EXCHANGE_COUNT;
+    const API_SOURCE: &'static [Exchange] = &API_SOURCE;
+    const SYMBOLS: &'static SymbolMap = &SYMBOLS;
+    const ALIASES: &'static AliasMap = &ALIASES;
+    const CCXT_DS_ID: Option<i64> = Some(profile::CCXT_DATA_SOURCE_ID);
+    const EXCLUDED_TOKENS: &'static [&'static str] = profile::EXCLUDED_TOKENS;
+    const COVERAGE: &'static [(&'static str, SymbolInfo)] = profile::COVERAGE;
+
+    fn data_source_id(exchange: Exchange) -> Option<i64> {
+        profile::data_source_id(&exchange.to_string())
+    }
+}
+
+os_common::price_script!(Script);
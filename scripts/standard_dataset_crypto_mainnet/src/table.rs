@@ -0,0 +1,270 @@
+// This file was automatically generated by os-gen from coverage.toml. Do not edit.
+
+const EXCHANGE_COUNT: u64 = 18;
+
+const API_SOURCE: [Exchange; 5] = [
+    Exchange::BRAVENEWCOIN,
+    Exchange::CRYPTOCOMPARE,
+    Exchange::COINGECKO,
+    Exchange::COINMARKETCAP,
+    Exchange::COINBASEPRO,
+];
+
+#[derive(Display, EnumString, EnumIter, Debug, Copy, Clone, PartialEq)]
+enum Exchange {
+    BRAVENEWCOIN = 0,
+    CRYPTOCOMPARE = 1,
+    COINGECKO = 2,
+    COINMARKETCAP = 3,
+    BINANCE = 4,
+    HUOBIPRO = 5,
+    COINBASEPRO = 6,
+    KRAKEN = 7,
+    BITFINEX = 8,
+    BITTREX = 9,
+    BITSTAMP = 10,
+    OKEX = 11,
+    FTX = 12,
+    HITBTC = 13,
+    ITBIT = 14,
+    BITHUMB = 15,
+    COINONE = 16,
+    BIBOX = 17,
+}
+
+static SYMBOLS: SymbolMap = phf_map! {
+    "BTC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, KRAKEN, BITFINEX, BITTREX], min_sources: 1 },
+    "ETH" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, KRAKEN, BITFINEX, BITTREX], min_sources: 1 },
+    "USDT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, COINBASEPRO, KRAKEN, BITFINEX, FTX], min_sources: 1 },
+    "XRP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, BITFINEX], min_sources: 1 },
+    "LINK" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, KRAKEN, BITTREX], min_sources: 1 },
+    "DOT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "BCH" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "LTC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, BITFINEX], min_sources: 1 },
+    "ADA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "BSV" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO, BITFINEX, OKEX], min_sources: 1 },
+    "CRO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "BNB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "EOS" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, BITFINEX], min_sources: 1 },
+    "XTZ" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, BITFINEX, BITTREX], min_sources: 1 },
+    "TRX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "XLM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, KRAKEN], min_sources: 1 },
+    "ATOM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO], min_sources: 1 },
+    "XMR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "OKB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "USDC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, KRAKEN, OKEX], min_sources: 1 },
+    "NEO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "XEM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "LEO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "HT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "VET" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "YFI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "MIOTA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "LEND" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO], min_sources: 1 },
+    "SNX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "DASH" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "COMP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, COINBASEPRO, KRAKEN, BITTREX], min_sources: 1 },
+    "ZEC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "ETC" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "OMG" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "MKR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "ONT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "NXM" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "AMPL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "BAT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, KRAKEN, BITTREX], min_sources: 1 },
+    "THETA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "DAI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, COINBASEPRO, KRAKEN, BITFINEX], min_sources: 1 },
+    "REN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "ZRX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "ALGO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "FTT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "DOGE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "KSM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "WAVES" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "EWT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "DGB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "KNC" => SymbolInfo { exchanges: exchanges![BINANCE, HUOBIPRO], min_sources: 1 },
+    "ICX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "TUSD" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "SUSHI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "BTT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "BAND" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "EGLD" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "ANT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "NMR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "USDP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "LSK" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "LRC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "HBAR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "BAL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "RUNE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "YFII" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "LUNA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "DCR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "SC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "STX" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "ENJ" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "BUSD" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "OCEAN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "RSR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "SXP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "BTG" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "BZRX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "SRM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "SNT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "SOL" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "CKB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "BNT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "CRV" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "MANA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "KAVA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "MATIC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "TRB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "REP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "FTM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "TOMO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "ONE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "WNXM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, BINANCE, HUOBIPRO], min_sources: 1 },
+    "PAXG" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "WAN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "SUSD" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "RLC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "OXT" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+    "RVN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "FNX" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+    "RENBTC" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "WBTC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "DIA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "BTM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "IOTX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "FET" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "JST" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MCO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINMARKETCAP], min_sources: 1 },
+    "KMD" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "BTS" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "QKC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "YAMV2" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "XZC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE], min_sources: 1 },
+    "UOS" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "AKRO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "HNT" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "HOT" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "KAI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "OGN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "WRX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "KDA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "ORN" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "FOR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "AST" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "STORJ" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, OKEX], min_sources: 1 },
+    "ABYSS" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "BLZ" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "BTU" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "CND" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "CVC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "DGX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "ELF" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "EQUAD" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "EURS" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "FXC" => SymbolInfo { exchanges: exchanges![], min_sources: 1 },
+    "GDC" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "GEN" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "GHT" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+    "GNO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "GVT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "IOST" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "KEY" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "LOOM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "MET" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MFG" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MLN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, HUOBIPRO], min_sources: 1 },
+    "MTL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "MYB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "NEXXO" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+    "NPXS" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "OST" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "PAY" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "PBTC" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "PLR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "PLTC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE], min_sources: 1 },
+    "PNK" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "PNT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "POLY" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "POWR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, COINBASEPRO, BITHUMB], min_sources: 1 },
+    "QNT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, COINBASEPRO], min_sources: 1 },
+    "RAE" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "REQ" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, COINBASEPRO], min_sources: 1 },
+    "RSV" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "SAN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "SPIKE" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "SPN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO], min_sources: 1 },
+    "STMX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "TKN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "TKX" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+    "TRYB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO], min_sources: 1 },
+    "UBT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "UPP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "USDS" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO], min_sources: 1 },
+    "VIDT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "XHV" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+    "CREAM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, FTX], min_sources: 1 },
+    "UNI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "LINA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HITBTC], min_sources: 1 },
+    "XVS" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "UMA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "CELO" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "QTUM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "HYN" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO], min_sources: 1 },
+    "ZIL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "ZB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO], min_sources: 1 },
+    "FIL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, OKEX, HITBTC], min_sources: 1 },
+    "ALPHA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, OKEX], min_sources: 1 },
+    "TWT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "PERP" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "DPI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MTA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "AAVE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "GRT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "KP3R" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, OKEX], min_sources: 1 },
+    "YAM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "PICKLE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "SFI" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "BOR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "OBTC" => SymbolInfo { exchanges: exchanges![COINGECKO], min_sources: 1 },
+    "CAKE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "HEGIC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "FRAX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "SCRT" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MVL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "STRK" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MIR" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "ANC" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "INDEX" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "ARPA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "AUTO" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "UST" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, COINBASEPRO, OKEX], min_sources: 1 },
+    "ALCX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, FTX, BIBOX], min_sources: 1 },
+    "OHM" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MIM" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MOVR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, KRAKEN], min_sources: 1 },
+    "AVAX" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, OKEX], min_sources: 1 },
+    "INJ" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, KRAKEN], min_sources: 1 },
+    "JOE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "ORCA" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "BEL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "ORC" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "SHIB" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, COINBASEPRO, OKEX], min_sources: 1 },
+    "AXS" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO], min_sources: 1 },
+    "ROSE" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "C98" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "CUSD" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "NEAR" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, HUOBIPRO, OKEX, HITBTC], min_sources: 1 },
+    "SAND" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, OKEX], min_sources: 1 },
+    "BOBA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BITFINEX, FTX], min_sources: 1 },
+    "BETA" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE], min_sources: 1 },
+    "SPELL" => SymbolInfo { exchanges: exchanges![CRYPTOCOMPARE, COINGECKO, COINMARKETCAP, BINANCE, COINBASEPRO, FTX], min_sources: 1 },
+};
+
+static ALIASES: AliasMap = phf_map! {
+    "IOTA" => "MIOTA",
+};
@@ -0,0 +1,56 @@
+// This file was automatically generated by os-gen from coverage.toml. Do not edit.
+
+const EXCHANGE_COUNT: u64 = 4;
+
+const API_SOURCE: [Exchange; 4] = [
+    Exchange::FIXER,
+    Exchange::OXR,
+    Exchange::XE,
+    Exchange::ALPHAVANTAGE,
+];
+
+#[derive(Display, EnumString, EnumIter, Debug, Copy, Clone, PartialEq)]
+enum Exchange {
+    FIXER = 0,
+    OXR = 1,
+    XE = 2,
+    ALPHAVANTAGE = 3,
+}
+
+static SYMBOLS: SymbolMap = phf_map! {
+    "EUR" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "GBP" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "CNY" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "KRW" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "JPY" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "INR" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "RUB" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "CHF" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "AUD" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "BRL" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "CAD" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "HKD" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "NZD" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "SEK" => SymbolInfo { exchanges: exchanges![FIXER, OXR, ALPHAVANTAGE], min_sources: 1 },
+    "SGD" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "ZAR" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "THB" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "XAU" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "XAG" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "XDR" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "MNT" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "NOK" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "IDR" => SymbolInfo { exchanges: exchanges![FIXER, OXR, ALPHAVANTAGE], min_sources: 1 },
+    "TRY" => SymbolInfo { exchanges: exchanges![FIXER, OXR, ALPHAVANTAGE], min_sources: 1 },
+    "TWD" => SymbolInfo { exchanges: exchanges![FIXER, OXR, ALPHAVANTAGE], min_sources: 1 },
+    "DKK" => SymbolInfo { exchanges: exchanges![FIXER, OXR, ALPHAVANTAGE], min_sources: 1 },
+    "PHP" => SymbolInfo { exchanges: exchanges![FIXER, OXR, ALPHAVANTAGE], min_sources: 1 },
+    "MYR" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "PLN" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "CZK" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+    "SAR" => SymbolInfo { exchanges: exchanges![FIXER, OXR, XE, ALPHAVANTAGE], min_sources: 1 },
+};
+
+static ALIASES: AliasMap = phf_map! {
+    "RMB" => "CNY",
+};
@@ -0,0 +1,38 @@
+// This file was automatically generated by os-gen from coverage.toml. Do not edit.
+// VERSION = 1
+
+const EXCHANGE_COUNT: u64 = 3;
+
+const API_SOURCE: [Exchange; 3] = [
+    Exchange::COINGECKO,
+    Exchange::COINMARKETCAP,
+    Exchange::CRYPTOCOMPARE,
+];
+
+#[derive(Display, EnumString, EnumIter, Debug, Copy, Clone, PartialEq)]
+enum Exchange {
+    COINGECKO = 0,
+    COINMARKETCAP = 1,
+    CRYPTOCOMPARE = 2,
+}
+
+static SYMBOLS: SymbolMap = phf_map! {
+    "APOLLO" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "GLOW" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MIR" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "ORION" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "SPEC" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "TWD" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "XDEFI" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "STT" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "VKR" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "TNS" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "ASTRO" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "MINE" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "PSI" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP], min_sources: 1 },
+    "LUNA" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+    "ANC" => SymbolInfo { exchanges: exchanges![COINGECKO, COINMARKETCAP, CRYPTOCOMPARE], min_sources: 1 },
+};
+
+static ALIASES: AliasMap = phf_map! {
+};
@@ -0,0 +1,164 @@
+// This file was automatically generated by os-gen from coverage.toml. Do not edit.
+// VERSION = 1
+
+const EXCHANGE_COUNT: u64 = 2;
+
+const API_SOURCE: [Exchange; 2] = [
+    Exchange::TERRASWAP,
+    Exchange::ASTROPORT,
+];
+
+#[derive(Display, EnumString, EnumIter, Debug, Copy, Clone, PartialEq)]
+enum Exchange {
+    TERRASWAP = 0,
+    ASTROPORT = 1,
+}
+
+static SYMBOLS: SymbolMap = phf_map! {
+    "ABR" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "ANC" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "APOLLO" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "ASTRO" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "ATLO" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "BRO" => SymbolInfo { exchanges: exchanges![ASTROPORT], min_sources: 1 },
+    "BTL" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "DPH" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "GLOW" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "HALO" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "KUJI" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "LOCAL" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "LOOP" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "LOOPR" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "LOTA" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "LUART" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "LUNI" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "LUV" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "LunaX" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "MARS" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "MIAW" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "MINE" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "MINT" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "MIR" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "MOON" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "ORION" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "ORNE" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "PLY" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "PRISM" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "Psi" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "ROBO" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "SAYVE" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "SDOLLAR" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "SITY" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "SPEC" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "STT" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "TFLOKI" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "TFTICII" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "TFTICIII" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "TLAND" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "TNS" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "TWD" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "VKR" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "WHALE" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "XDEFI" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "XRUNE" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "XTRA" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "aUST" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "bETH" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "bLuna" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "cLuna" => SymbolInfo { exchanges: exchanges![ASTROPORT], min_sources: 1 },
+    "mAAPL" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mABNB" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mAMD" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mAMZN" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mARKK" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mBABA" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mBTC" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mCOIN" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mDIS" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mDOT" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mETH" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mFB" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mGLXY" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mGOOGL" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mGS" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mHOOD" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mIAU" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mJNJ" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mKO" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mMSFT" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mNFLX" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mNIO" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mNKE" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mNVDA" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mPYPL" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mQQQ" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mSBUX" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mSLV" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mSPY" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mSQ" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mTSLA" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mTWTR" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mUSO" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "mVIXY" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "pLuna" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "vUST" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "wasAVAX" => SymbolInfo { exchanges: exchanges![ASTROPORT], min_sources: 1 },
+    "wbWBNB" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "weUSDC" => SymbolInfo { exchanges: exchanges![ASTROPORT], min_sources: 1 },
+    "wewstETH" => SymbolInfo { exchanges: exchanges![TERRASWAP, ASTROPORT], min_sources: 1 },
+    "whSD" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "wsSOL" => SymbolInfo { exchanges: exchanges![TERRASWAP], min_sources: 1 },
+    "wsstSOL" => SymbolInfo { exchanges: exchanges![ASTROPORT], min_sources: 1 },
+};
+
+static ALIASES: AliasMap = phf_map! {
+    "AUST" => "aUST",
+    "BETH" => "bETH",
+    "BLUNA" => "bLuna",
+    "CLUNA" => "cLuna",
+    "LUNAX" => "LunaX",
+    "MAAPL" => "mAAPL",
+    "MABNB" => "mABNB",
+    "MAMD" => "mAMD",
+    "MAMZN" => "mAMZN",
+    "MARKK" => "mARKK",
+    "MBABA" => "mBABA",
+    "MBTC" => "mBTC",
+    "MCOIN" => "mCOIN",
+    "MDIS" => "mDIS",
+    "MDOT" => "mDOT",
+    "METH" => "mETH",
+    "MFB" => "mFB",
+    "MGLXY" => "mGLXY",
+    "MGOOGL" => "mGOOGL",
+    "MGS" => "mGS",
+    "MHOOD" => "mHOOD",
+    "MIAU" => "mIAU",
+    "MJNJ" => "mJNJ",
+    "MKO" => "mKO",
+    "MMSFT" => "mMSFT",
+    "MNFLX" => "mNFLX",
+    "MNIO" => "mNIO",
+    "MNKE" => "mNKE",
+    "MNVDA" => "mNVDA",
+    "MPYPL" => "mPYPL",
+    "MQQQ" => "mQQQ",
+    "MSBUX" => "mSBUX",
+    "MSLV" => "mSLV",
+    "MSPY" => "mSPY",
+    "MSQ" => "mSQ",
+    "MTSLA" => "mTSLA",
+    "MTWTR" => "mTWTR",
+    "MUSO" => "mUSO",
+    "MVIXY" => "mVIXY",
+    "PLUNA" => "pLuna",
+    "PSI" => "Psi",
+    "VUST" => "vUST",
+    "WASAVAX" => "wasAVAX",
+    "WBWBNB" => "wbWBNB",
+    "WEUSDC" => "weUSDC",
+    "WEWSTETH" => "wewstETH",
+    "WHSD" => "whSD",
+    "WSSOL" => "wsSOL",
+    "WSSTSOL" => "wsstSOL",
+};
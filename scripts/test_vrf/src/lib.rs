@@ -1,6 +1,9 @@
 use obi::{OBIDecode, OBIEncode, OBISchema};
-use owasm_kit::{execute_entry_point, ext, oei, prepare_entry_point};
-use sha3::{Digest, Sha3_256};
+use os_common::host::{execute_entry_point, ext, oei, prepare_entry_point};
+use os_common::report::parse_vrf_report;
+use os_common::vrf::get_random_ds_index_from_seed;
+use os_common::ScriptError;
+use std::num::ParseIntError;
 
 #[derive(OBIDecode, OBISchema)]
 struct Input {
@@ -13,130 +16,114 @@ struct Output {
     hash: Vec<u8>,
 }
 
-const NUM_DS: u8 = 4;
+/// The on-chain `{input}/{output}` schema, recorded in schemas.json.
+pub fn schema() -> String {
+    os_common::obi_schema::<Input, Output>()
+}
+
+const NUM_DS: usize = 4;
 
 const VRF_1: i64 = 82;
 const VRF_2: i64 = 83;
 const VRF_3: i64 = 84;
 const VRF_4: i64 = 85;
 
-fn decode_hex(input: &str) -> Vec<u8> {
+fn decode_hex(input: &str) -> Result<Vec<u8>, ParseIntError> {
     (0..input.len())
         .step_by(2)
-        .map(|i| u8::from_str_radix(&input[i..i + 2], 16).unwrap())
+        .map(|i| u8::from_str_radix(&input[i..i + 2], 16))
         .collect()
 }
 
-fn get_pubkey_by_id(i: u8) -> String {
-    match i {
-        0 => "dca4c01a68b79c82ef53a4c400b7020a92afa58c2c2a514f33d1153e577ad3b7".into(),
-        1 => "6bf857a5e0a33655707e764bd6a40896e1bfaec9d520cd87a17051606fe96fc7".into(),
-        2 => "66217ae09f2a8ff5f0a74b69aab1a45b3713559d65013ec52c6a76e0f2c18378".into(),
-        3 => "0b6ebe53e0e8665f43a6836fedacf22fb0b19f1136e90bf0e1705c5a1cf06460".into(),
-        _ => panic!("Unknown index"),
-    }
-}
-
-fn get_hash(x: &[u8]) -> Vec<u8> {
-    let mut hasher = Sha3_256::new();
-    hasher.update(x);
-    let mut output = vec![0u8; 32];
-    output.copy_from_slice(&hasher.finalize());
-    output
+fn get_pubkey_by_id(i: usize) -> Result<Vec<u8>, ScriptError> {
+    let pubkey = match i {
+        0 => "dca4c01a68b79c82ef53a4c400b7020a92afa58c2c2a514f33d1153e577ad3b7",
+        1 => "6bf857a5e0a33655707e764bd6a40896e1bfaec9d520cd87a17051606fe96fc7",
+        2 => "66217ae09f2a8ff5f0a74b69aab1a45b3713559d65013ec52c6a76e0f2c18378",
+        3 => "0b6ebe53e0e8665f43a6836fedacf22fb0b19f1136e90bf0e1705c5a1cf06460",
+        _ => return Err(ScriptError::UnknownVrfProvider(i)),
+    };
+    decode_hex(pubkey).map_err(|_| ScriptError::InvalidVrfPubkey(format!("VRF_{}", i + 1)))
 }
 
-fn get_random_ds_index_from_seed(s: &str, num_data_source: u8) -> u8 {
-    let nds = num_data_source as u16;
-
-    // compute remainders for [2^0,2^1,2^2,...,2^31] mod nds
-    let pre_compute_remainders = (0..32).fold(vec![0u16; 32], |mut s, i| {
-        s[31 - i] = match i {
-            0 => 1,
-            _ => (s[31 + 1 - i] * 256) % nds,
-        };
-        s
-    });
-
-    // selected data source index = random_hash_from_seed % NUM_DS
-    get_hash(s.as_bytes())
-        .iter()
-        .map(|&x| x as u16)
-        .zip(pre_compute_remainders.iter())
-        .fold(0u16, |selected_ds_i, (h, r)| {
-            (((h * r) % nds) + selected_ds_i) % nds
-        }) as u8
-}
-
-fn mod_index_to_ds_id(i: u8) -> i64 {
+fn mod_index_to_ds_id(i: usize) -> Result<i64, ScriptError> {
     match i {
-        0 => VRF_1,
-        1 => VRF_2,
-        2 => VRF_3,
-        3 => VRF_4,
-        _ => panic!("Unknown index"),
+        0 => Ok(VRF_1),
+        1 => Ok(VRF_2),
+        2 => Ok(VRF_3),
+        3 => Ok(VRF_4),
+        _ => Err(ScriptError::UnknownVrfProvider(i)),
     }
 }
 
 #[no_mangle]
-fn prepare_impl(input: Input) {
+fn prepare_impl(input: Input) -> Result<(), ScriptError> {
     let s = format!("{} {}", input.seed, input.time);
     oei::ask_external_data(
         1,
-        mod_index_to_ds_id(get_random_ds_index_from_seed(&s, NUM_DS)),
+        mod_index_to_ds_id(get_random_ds_index_from_seed(&s, NUM_DS))?,
         s.as_bytes(),
     );
-}
-
-fn str_to_vec(input: &String) -> Vec<u8> {
-    (0..input.len())
-        .step_by(2)
-        .map(|i| u8::from_str_radix(&input[i..i + 2], 16).unwrap())
-        .collect()
+    Ok(())
 }
 
 #[no_mangle]
-fn execute_impl(input: Input) -> Output {
-    let x = ext::load_majority::<String>(1).unwrap();
+fn execute_impl(input: Input) -> Result<Output, ScriptError> {
+    let x = ext::require_majority::<String>(1)?;
 
-    assert!(x.len() == 288);
     // The first 160 characters is the proof
-    let proof = decode_hex(&x[0..160]);
+    let (proof, result) = parse_vrf_report(&x).ok_or(ScriptError::MalformedReport(1))?;
 
-    let pubkey = get_pubkey_by_id(get_random_ds_index_from_seed(&format!("{} {}", input.seed, input.time), NUM_DS));
+    let pubkey = get_pubkey_by_id(get_random_ds_index_from_seed(&format!("{} {}", input.seed, input.time), NUM_DS))?;
     let alpha = format!("{}:{}", input.seed, input.time);
 
-    if !oei::ecvrf_verify(&decode_hex(&pubkey), &proof, alpha.as_bytes()) {
-        panic!("Invalid result")
+    match oei::ecvrf_verify(&pubkey, &proof, alpha.as_bytes()) {
+        Ok(true) => {}
+        Ok(false) => return Err(ScriptError::InvalidVrfProof),
+        Err(err_code) => return Err(ScriptError::VrfVerification(err_code)),
     }
 
-    Output {
-        hash: str_to_vec(&x),
-    }
+    Ok(Output {
+        hash: [proof, result].concat(),
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use obi::get_schema;
-    use std::collections::*;
+    use os_common::host::replay::replay_fixtures;
+    use os_common::schema::check_schema;
+
+    #[test]
+    fn test_fixtures() {
+        replay_fixtures(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"),
+            env!("CARGO_PKG_NAME"),
+            prepare,
+            execute,
+        );
+    }
 
     #[test]
     fn test_get_schema() {
-        let mut schema = HashMap::new();
-        Input::add_definitions_recursively(&mut schema);
-        Output::add_definitions_recursively(&mut schema);
-        let input_schema = get_schema(String::from("Input"), &schema);
-        let output_schema = get_schema(String::from("Output"), &schema);
-        println!("{}/{}", input_schema, output_schema);
-        // assert_eq!(
-        //     "{public_key:string,seed:string,time:u64}/{hash:bytes}",
-        //     format!("{}/{}", input_schema, output_schema),
-        // );
+        check_schema(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../schemas.json"),
+            env!("CARGO_PKG_NAME"),
+            &schema(),
+        );
+    }
+
+    #[test]
+    fn test_unknown_provider() {
+        assert_eq!(mod_index_to_ds_id(3), Ok(VRF_4));
+        assert_eq!(mod_index_to_ds_id(NUM_DS), Err(ScriptError::UnknownVrfProvider(NUM_DS)));
+        assert_eq!(get_pubkey_by_id(0).map(|pubkey| pubkey.len()), Ok(32));
+        assert_eq!(get_pubkey_by_id(NUM_DS), Err(ScriptError::UnknownVrfProvider(NUM_DS)));
     }
 
     fn assert_all(input: String, expected_outputs: Vec<u8>) {
         for (i, n_ds) in expected_outputs.iter().zip(2..255) {
-            assert_eq!(*i, get_random_ds_index_from_seed(&input, n_ds));
+            assert_eq!(*i as usize, get_random_ds_index_from_seed(&input, n_ds));
         }
     }
 
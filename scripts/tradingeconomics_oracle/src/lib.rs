@@ -0,0 +1,70 @@
+use obi::{OBIDecode, OBIEncode, OBISchema};
+use os_common::host::{execute_entry_point, ext, oei, prepare_entry_point};
+use os_common::report::parse_key_values;
+use os_common::ScriptError;
+
+#[derive(OBIDecode, OBISchema)]
+struct Input {
+    _null: u8,
+}
+
+#[derive(OBIEncode, OBISchema)]
+struct Output {
+    keys: Vec<String>,
+    values: Vec<u64>,
+}
+
+/// The on-chain `{input}/{output}` schema, recorded in schemas.json.
+pub fn schema() -> String {
+    os_common::obi_schema::<Input, Output>()
+}
+
+const DATA_SOURCE_ID: i64 = 24;
+const EXTERNAL_ID: i64 = 0;
+
+#[no_mangle]
+fn prepare_impl(_input: Input) -> Result<(), ScriptError> {
+    oei::ask_external_data(EXTERNAL_ID, DATA_SOURCE_ID, b"");
+    Ok(())
+}
+
+#[no_mangle]
+fn execute_impl(_input: Input) -> Result<Output, ScriptError> {
+    let majority = ext::require_majority::<String>(EXTERNAL_ID)?;
+
+    let (keys, values) = parse_key_values(&majority)
+        .ok_or(ScriptError::MalformedReport(EXTERNAL_ID))?
+        .into_iter()
+        .unzip();
+
+    Ok(Output { keys, values })
+}
+
+prepare_entry_point!(prepare_impl);
+execute_entry_point!(execute_impl);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_common::host::replay::replay_fixtures;
+    use os_common::schema::check_schema;
+
+    #[test]
+    fn test_fixtures() {
+        replay_fixtures(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"),
+            env!("CARGO_PKG_NAME"),
+            prepare,
+            execute,
+        );
+    }
+
+    #[test]
+    fn test_schema() {
+        check_schema(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../schemas.json"),
+            env!("CARGO_PKG_NAME"),
+            &schema(),
+        );
+    }
+}
@@ -1,12 +1,14 @@
 use obi::{OBIDecode, OBIEncode, OBISchema};
-use owasm_kit::{execute_entry_point, ext, oei, prepare_entry_point};
-use sha3::{Digest, Sha3_256};
-use hex;
+use os_common::host::{execute_entry_point, ext, oei, prepare_entry_point};
+use os_common::report::parse_vrf_report;
+use os_common::vrf::get_random_ds_index_from_seed;
+use os_common::ScriptError;
 
 #[derive(OBIDecode, OBISchema)]
 struct Input {
     seed: Vec<u8>,
     time: u64,
+    #[allow(dead_code)]
     worker_address: Vec<u8>, // The worker should use this field to prevent front-running.
 }
 
@@ -16,84 +18,73 @@ struct Output {
     result: Vec<u8>,
 }
 
-const NUM_DS: u8 = 4;
-const VRF_1: i64 = 94;
-const VRF_2: i64 = 95;
-const VRF_3: i64 = 96;
-const VRF_4: i64 = 97;
-
-fn get_hash(x: &[u8]) -> Vec<u8> {
-    let mut hasher = Sha3_256::new();
-    hasher.update(x);
-    let mut output = vec![0u8; 32];
-    output.copy_from_slice(&hasher.finalize());
-    output
+/// The on-chain `{input}/{output}` schema, recorded in schemas.json.
+pub fn schema() -> String {
+    os_common::obi_schema::<Input, Output>()
 }
 
-fn get_random_ds_index_from_seed(s: &str, num_data_source: u8) -> u8 {
-    let nds = num_data_source as u16;
-
-    // compute remainders for [2^0,2^1,2^2,...,2^31] mod nds
-    let pre_compute_remainders = (0..32).fold(vec![0u16; 32], |mut s, i| {
-        s[31 - i] = match i {
-            0 => 1,
-            _ => (s[31 + 1 - i] * 256) % nds,
-        };
-        s
-    });
-
-    // selected data source index = random_hash_from_seed % NUM_DS
-    get_hash(s.as_bytes())
-        .iter()
-        .map(|&x| x as u16)
-        .zip(pre_compute_remainders.iter())
-        .fold(0u16, |selected_ds_i, (h, r)| {
-            (((h * r) % nds) + selected_ds_i) % nds
-        }) as u8
+// VRF data sources of the network selected through `BAND_NETWORK` at build
+// time, see profiles/
+#[allow(dead_code)]
+mod profile {
+    include!(concat!(env!("OUT_DIR"), "/profile.rs"));
 }
 
-fn mod_index_to_ds_id(i: u8) -> i64 {
-    match i {
-        0 => VRF_1,
-        1 => VRF_2,
-        2 => VRF_3,
-        3 => VRF_4,
-        _ => panic!("Unknown index"),
+fn mod_index_to_ds_id(i: usize) -> Result<i64, ScriptError> {
+    match profile::VRF_PROVIDERS.get(i) {
+        Some(provider) => Ok(provider.data_source_id),
+        None => Err(ScriptError::UnknownVrfProvider(i)),
     }
 }
 
 #[no_mangle]
-fn prepare_impl(input: Input) {
-    assert!(input.seed.len() <= 32);
+fn prepare_impl(input: Input) -> Result<(), ScriptError> {
+    if input.seed.len() > 32 {
+        return Err(ScriptError::InvalidInput(
+            "seed is longer than 32 bytes".into(),
+        ));
+    }
 
     let s = format!("{} {}", hex::encode(input.seed), input.time);
     oei::ask_external_data(
         1,
-        mod_index_to_ds_id(get_random_ds_index_from_seed(&s, NUM_DS)),
+        mod_index_to_ds_id(get_random_ds_index_from_seed(
+            &s,
+            profile::VRF_PROVIDERS.len(),
+        ))?,
         s.as_bytes(),
     );
+    Ok(())
 }
 
 #[no_mangle]
-fn execute_impl(_input: Input) -> Output {
-    let concat_data = ext::load_majority::<String>(1).unwrap();
-    assert_eq!(concat_data.len(), 288);
-
-    Output {
-        // The first 160 characters is the proof
-        proof: hex::decode(&concat_data[0..160]).unwrap().into(),
-        // The last 128 characters is the result
-        result: hex::decode(&concat_data[160..288]).unwrap().into(),
-    }
+fn execute_impl(_input: Input) -> Result<Output, ScriptError> {
+    let concat_data = ext::require_majority::<String>(1)?;
+    // The first 160 characters is the proof, the last 128 the result
+    let (proof, result) = parse_vrf_report(&concat_data).ok_or(ScriptError::MalformedReport(1))?;
+
+    Ok(Output { proof, result })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use os_common::host::replay::replay_fixtures;
+    use os_common::schema::check_schema;
+
+    #[test]
+    fn test_fixtures() {
+        replay_fixtures(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"),
+            env!("CARGO_PKG_NAME"),
+            prepare,
+            execute,
+        );
+    }
 
     fn assert_all(input: String, expected_outputs: Vec<u8>) {
         for (i, n_ds) in expected_outputs.iter().zip(2..255) {
-            assert_eq!(*i, get_random_ds_index_from_seed(&input, n_ds));
+            assert_eq!(*i as usize, get_random_ds_index_from_seed(&input, n_ds));
         }
     }
 
@@ -166,6 +157,15 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_schema() {
+        check_schema(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../schemas.json"),
+            env!("CARGO_PKG_NAME"),
+            &schema(),
+        );
+    }
 }
 
 prepare_entry_point!(prepare_impl);
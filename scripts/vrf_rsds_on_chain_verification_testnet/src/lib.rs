@@ -0,0 +1,94 @@
+use obi::{OBIDecode, OBIEncode, OBISchema};
+use os_common::host::{execute_entry_point, ext, oei, prepare_entry_point};
+use os_common::{Decimal, ScriptError, Strategy};
+
+#[derive(OBIDecode, OBISchema)]
+struct Input {
+    base: String,
+    quote: String,
+    timestamp: u64,
+    multiplier: u64,
+}
+
+#[derive(OBIEncode, OBISchema)]
+struct Output {
+    price: u64,
+}
+
+/// The on-chain `{input}/{output}` schema, recorded in schemas.json.
+pub fn schema() -> String {
+    os_common::obi_schema::<Input, Output>()
+}
+
+const D1: i64 = 486;
+const D2: i64 = 487;
+const D3: i64 = 488;
+
+fn prepare_impl(input: Input) -> Result<(), ScriptError> {
+    let calldata = format!("{} {} {}", input.base, input.quote, input.timestamp);
+    oei::ask_external_data(D1, D1, calldata.as_bytes());
+    oei::ask_external_data(D2, D2, calldata.as_bytes());
+    oei::ask_external_data(D3, D3, calldata.as_bytes());
+    Ok(())
+}
+
+fn execute_impl(input: Input) -> Result<Output, ScriptError> {
+    let mut prices = Vec::new();
+    for eid in [D1, D2, D3] {
+        // A report that is not a price, or a price of 0, is left out rather
+        // than failing the request, which any single validator could
+        // otherwise do
+        for report in ext::load_input::<String>(eid) {
+            match report.parse::<Decimal>() {
+                Ok(px) if px != Decimal::ZERO => prices.push(px),
+                _ => {}
+            }
+        }
+    }
+    let pair = format!("{}/{}", input.base, input.quote);
+    // Without any report there is no price, rather than a price of 0
+    let median = match Strategy::Median.aggregate_values(&mut prices) {
+        Some(median) => median,
+        None => return Err(ScriptError::NoPrice(pair)),
+    };
+    // The output encodes the price as a u64
+    let price = median
+        .scale(input.multiplier)
+        .and_then(|price| u64::try_from(price).ok());
+    let price = match price {
+        // A price too small for the multiplier is no price either
+        Some(0) => return Err(ScriptError::NoPrice(pair)),
+        Some(price) => price,
+        None => return Err(ScriptError::RateOverflow(pair)),
+    };
+    Ok(Output { price })
+}
+
+prepare_entry_point!(prepare_impl);
+execute_entry_point!(execute_impl);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_common::host::replay::replay_fixtures;
+    use os_common::schema::check_schema;
+
+    #[test]
+    fn test_fixtures() {
+        replay_fixtures(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"),
+            env!("CARGO_PKG_NAME"),
+            prepare,
+            execute,
+        );
+    }
+
+    #[test]
+    fn test_schema() {
+        check_schema(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../schemas.json"),
+            env!("CARGO_PKG_NAME"),
+            &schema(),
+        );
+    }
+}
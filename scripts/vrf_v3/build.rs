@@ -0,0 +1,3 @@
+fn main() {
+    os_build::emit_network_profile();
+}
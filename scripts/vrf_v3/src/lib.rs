@@ -0,0 +1,101 @@
+use obi::{OBIDecode, OBIEncode, OBISchema};
+use os_common::host::{execute_entry_point, ext, oei, prepare_entry_point};
+use os_common::report::parse_vrf_report;
+use os_common::vrf::{get_hash, get_random_ds_index_from_seed};
+use os_common::ScriptError;
+
+// An Oracle Script featuring on-chain verification and random DS selection.
+
+#[derive(OBIDecode, OBISchema)]
+struct Input {
+    seed: Vec<u8>,
+    time: u64, // In Unix time
+}
+
+#[derive(OBIEncode, OBISchema)]
+struct Output {
+    result: Vec<u8>,
+}
+
+/// The on-chain `{input}/{output}` schema, recorded in schemas.json.
+pub fn schema() -> String {
+    os_common::obi_schema::<Input, Output>()
+}
+
+// VRF providers of the network selected through `BAND_NETWORK` at build
+// time, see profiles/
+#[allow(dead_code)]
+mod profile {
+    include!(concat!(env!("OUT_DIR"), "/profile.rs"));
+}
+
+fn get_ds_from_input(ds_input: &str) -> &'static profile::VrfProvider {
+    &profile::VRF_PROVIDERS[get_random_ds_index_from_seed(ds_input, profile::VRF_PROVIDERS.len())]
+}
+
+fn prepare_impl(input: Input) -> Result<(), ScriptError> {
+    if input.seed.len() != 32 {
+        return Err(ScriptError::InvalidInput("seed must be bytes32".into()));
+    }
+
+    if input.time < 1 {
+        return Err(ScriptError::InvalidInput("time must be > 0".into()));
+    }
+
+    let ds_input = format!("{} {}", hex::encode(input.seed), input.time);
+    oei::ask_external_data(
+        1,
+        get_ds_from_input(&ds_input).data_source_id,
+        ds_input.as_bytes(),
+    );
+    Ok(())
+}
+
+fn execute_impl(input: Input) -> Result<Output, ScriptError> {
+    let concat_data = ext::require_majority::<String>(1)?;
+    // The first 160 characters is the proof, the last 128 the result
+    let (proof, result) = parse_vrf_report(&concat_data).ok_or(ScriptError::MalformedReport(1))?;
+
+    let ds_input = format!("{} {}", hex::encode(input.seed), input.time);
+    let provider = get_ds_from_input(&ds_input);
+    let pubkey = hex::decode(provider.pubkey)
+        .map_err(|_| ScriptError::InvalidVrfPubkey(provider.name.into()))?;
+    let verification_result = oei::ecvrf_verify(&pubkey, &proof, ds_input.as_bytes());
+
+    match verification_result {
+        Ok(true) => Ok(Output {
+            result: get_hash(&result),
+        }),
+        Ok(false) => Err(ScriptError::InvalidVrfProof),
+        Err(err_code) => Err(ScriptError::VrfVerification(err_code)),
+    }
+}
+
+prepare_entry_point!(prepare_impl);
+execute_entry_point!(execute_impl);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use os_common::host::replay::replay_fixtures;
+    use os_common::schema::check_schema;
+
+    #[test]
+    fn test_fixtures() {
+        replay_fixtures(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../fixtures"),
+            env!("CARGO_PKG_NAME"),
+            prepare,
+            execute,
+        );
+    }
+
+    #[test]
+    fn test_schema() {
+        check_schema(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../schemas.json"),
+            env!("CARGO_PKG_NAME"),
+            &schema(),
+        );
+    }
+}